@@ -1,13 +1,15 @@
 /// Letter bitmask type (far outstrips HashSet<char> in terms of performance).
 pub type LetterBitmask = u64;
 
+/// The number of letters in the alphabet (words consist only of ASCII letters).
+pub const ALPHABET_SIZE: usize = 26;
+
+/// The bitmask of every letter in the alphabet.
+pub const ALL_LETTERS: LetterBitmask = (1 << ALPHABET_SIZE) - 1;
+
 /// Trait extension to allow easy computation of letter bitmasks.
 pub trait CanRepresentLetterBitmask {
     fn char_bitmask(ch: &char) -> Self;
-
-    fn compute_bitmask<'a, T>(word: T) -> Self
-    where
-        T: Iterator<Item = &'a char>;
 }
 
 impl CanRepresentLetterBitmask for LetterBitmask {
@@ -15,27 +17,18 @@ impl CanRepresentLetterBitmask for LetterBitmask {
     fn char_bitmask(ch: &char) -> Self {
         1_u64 << (ch.to_ascii_lowercase() as u8 - 'a' as u8)
     }
-
-    fn compute_bitmask<'a, T>(word: T) -> Self
-    where
-        T: Iterator<Item = &'a char>,
-    {
-        let mut output = 0_u64;
-        for ch in word {
-            output |= LetterBitmask::char_bitmask(ch);
-        }
-
-        output
-    }
 }
 
 /// Trait extension to enable easy conversion of Vec<char> to letter bitmasks.
+#[cfg(test)]
 pub trait CanConvertToLetterBitmask {
     fn to_letter_bitmask(&self) -> LetterBitmask;
 }
 
+#[cfg(test)]
 impl CanConvertToLetterBitmask for Vec<char> {
     fn to_letter_bitmask(&self) -> LetterBitmask {
-        LetterBitmask::compute_bitmask(self.iter())
+        self.iter()
+            .fold(0, |mask, ch| mask | LetterBitmask::char_bitmask(ch))
     }
 }
@@ -0,0 +1,126 @@
+/// The number of indices stored per block of a `WordBitset`.
+pub const BLOCK_BITS: usize = u64::BITS as usize;
+
+/// A set of indices into a word list of length `len`, stored as one bit per word.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WordBitset {
+    blocks: Vec<u64>,
+    len: usize,
+}
+
+impl WordBitset {
+    /// An empty set of indices into a word list of length `len`.
+    pub fn empty(len: usize) -> Self {
+        WordBitset {
+            blocks: vec![0_u64; len.div_ceil(BLOCK_BITS)],
+            len,
+        }
+    }
+
+    /// Add index `idx` to the set.
+    #[inline(always)]
+    pub fn insert(&mut self, idx: usize) {
+        debug_assert!(idx < self.len, "Bitset index out of bounds!");
+        self.blocks[idx / BLOCK_BITS] |= 1_u64 << (idx % BLOCK_BITS);
+    }
+
+    /// The number of indices in the set.
+    pub fn count(&self) -> usize {
+        self.blocks
+            .iter()
+            .map(|block| block.count_ones() as usize)
+            .sum()
+    }
+
+    /// A set of indices into a word list of length `len`, made up of the given `blocks` of
+    /// `BLOCK_BITS` indices each.
+    pub fn from_blocks(blocks: Vec<u64>, len: usize) -> Self {
+        assert_eq!(
+            blocks.len(),
+            len.div_ceil(BLOCK_BITS),
+            "Wrong number of blocks!"
+        );
+        WordBitset { blocks, len }
+    }
+
+    /// The blocks of `BLOCK_BITS` indices making up the set.
+    #[inline(always)]
+    pub fn blocks(&self) -> &[u64] {
+        &self.blocks
+    }
+
+    /// The block at `block_idx` of the set of every index into a word list of length `len`.
+    #[inline(always)]
+    pub fn full_block(len: usize, block_idx: usize) -> u64 {
+        match len - block_idx * BLOCK_BITS {
+            remaining if remaining >= BLOCK_BITS => u64::MAX,
+            remaining => (1_u64 << remaining) - 1,
+        }
+    }
+
+    /// An iterator over the indices in the set, in increasing order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.blocks
+            .iter()
+            .enumerate()
+            .flat_map(|(block_idx, &block)| {
+                let mut block = block;
+                std::iter::from_fn(move || {
+                    if block == 0 {
+                        return None;
+                    }
+
+                    let bit = block.trailing_zeros() as usize;
+                    block &= block - 1;
+                    Some(block_idx * BLOCK_BITS + bit)
+                })
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_word_bitset() {
+        let mut full = WordBitset::empty(130);
+        (0..130).for_each(|idx| full.insert(idx));
+        assert_eq!(full.count(), 130);
+        assert_eq!(
+            full.iter().collect::<Vec<_>>(),
+            (0..130).collect::<Vec<_>>()
+        );
+
+        let mut evens = WordBitset::empty(130);
+        let mut threes = WordBitset::empty(130);
+        for idx in 0..130 {
+            if idx % 2 == 0 {
+                evens.insert(idx);
+            }
+            if idx % 3 == 0 {
+                threes.insert(idx);
+            }
+        }
+
+        assert_eq!(evens.count(), 65);
+        let sixes = WordBitset::from_blocks(
+            evens
+                .blocks()
+                .iter()
+                .zip(threes.blocks())
+                .map(|(a, b)| a & b)
+                .collect(),
+            130,
+        );
+        assert_eq!(
+            sixes.iter().collect::<Vec<_>>(),
+            (0..130).step_by(6).collect::<Vec<_>>()
+        );
+
+        for (block_idx, block) in full.blocks().iter().enumerate() {
+            assert_eq!(*block, WordBitset::full_block(130, block_idx));
+        }
+        assert_eq!(WordBitset::full_block(130, 2), 0b11);
+    }
+}
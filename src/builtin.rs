@@ -0,0 +1,131 @@
+use std::{path::PathBuf, str::FromStr};
+
+use crate::words::{Wordlist, WordlistPtr};
+
+/// The prefix selecting a built-in wordlist by name wherever a wordlist file is expected.
+pub const BUILTIN_PREFIX: &str = "builtin:";
+
+/// The built-in list used for guesses unless another is given.
+pub const DEFAULT_GUESS_LIST: &str = "guesses";
+
+/// The built-in list used for answers unless another is given.
+pub const DEFAULT_ANSWER_LIST: &str = "answers";
+
+/// A wordlist compiled into the binary, in the same format as wordlist files.
+pub struct BuiltinWordlist {
+    pub name: &'static str,
+    pub description: &'static str,
+    contents: &'static str,
+}
+
+impl std::fmt::Debug for BuiltinWordlist {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", BUILTIN_PREFIX, self.name)
+    }
+}
+
+impl PartialEq for BuiltinWordlist {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl Eq for BuiltinWordlist {}
+
+impl BuiltinWordlist {
+    /// Parse this list into a `Wordlist`.
+    pub fn load(&self) -> WordlistPtr {
+        Wordlist::parse(self.contents).expect("Built-in wordlist is malformed!")
+    }
+}
+
+/// The wordlists compiled into the binary (none unless built with `embedded-wordlists`).
+#[cfg(feature = "embedded-wordlists")]
+pub const BUILTIN_WORDLISTS: &[BuiltinWordlist] = &[
+    BuiltinWordlist {
+        name: DEFAULT_ANSWER_LIST,
+        description: "Answers of the original Wordle, scored by word frequency",
+        contents: include_str!("../data/answerlist.txt"),
+    },
+    BuiltinWordlist {
+        name: DEFAULT_GUESS_LIST,
+        description: "Allowed guesses of the original Wordle, scored by word frequency",
+        contents: include_str!("../data/guesslist.txt"),
+    },
+];
+
+/// The wordlists compiled into the binary (none unless built with `embedded-wordlists`).
+#[cfg(not(feature = "embedded-wordlists"))]
+pub const BUILTIN_WORDLISTS: &[BuiltinWordlist] = &[];
+
+/// Find the built-in wordlist called `name`, if there is one.
+pub fn builtin_wordlist(name: &str) -> Option<&'static BuiltinWordlist> {
+    BUILTIN_WORDLISTS.iter().find(|list| list.name == name)
+}
+
+/// Where a wordlist is loaded from: a file, or a list built into the binary. Parsed from a
+/// path, or from a built-in list's name prefixed with `builtin:`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WordlistSource {
+    File(PathBuf),
+    Builtin(&'static BuiltinWordlist),
+}
+
+impl WordlistSource {
+    /// The built-in list called `name` as a source, if there is one.
+    pub fn builtin(name: &str) -> Option<Self> {
+        builtin_wordlist(name).map(WordlistSource::Builtin)
+    }
+
+    /// Load the wordlist from this source.
+    pub fn load(&self) -> Result<WordlistPtr, std::io::Error> {
+        match self {
+            WordlistSource::File(path) => Wordlist::init(path),
+            WordlistSource::Builtin(list) => Ok(list.load()),
+        }
+    }
+}
+
+impl FromStr for WordlistSource {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix(BUILTIN_PREFIX) {
+            Some(name) => WordlistSource::builtin(name).ok_or_else(|| {
+                let names: Vec<&str> = BUILTIN_WORDLISTS.iter().map(|list| list.name).collect();
+                format!(
+                    "Unknown built-in wordlist '{}' (available: {})",
+                    name,
+                    names.join(", ")
+                )
+            }),
+            None => Ok(WordlistSource::File(PathBuf::from(s))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wordlist_source() {
+        assert_eq!(
+            "data/answerlist.txt".parse(),
+            Ok(WordlistSource::File(PathBuf::from("data/answerlist.txt")))
+        );
+        assert!("builtin:nonexistent".parse::<WordlistSource>().is_err());
+
+        #[cfg(feature = "embedded-wordlists")]
+        {
+            use crate::words::HasWords;
+
+            let source: WordlistSource = "builtin:answers".parse().unwrap();
+            assert_eq!(
+                source,
+                WordlistSource::builtin(DEFAULT_ANSWER_LIST).unwrap()
+            );
+            assert_eq!(source.load().unwrap().possible_words().len(), 2315);
+        }
+    }
+}
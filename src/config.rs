@@ -0,0 +1,190 @@
+use serde::Deserialize;
+use std::{
+    env, fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use crustacean_wordle::builtin::BUILTIN_PREFIX;
+
+/// The name of the per-project config file, looked up in the current directory and its
+/// ancestors.
+const PROJECT_CONFIG_FILE: &str = "crustacean-wordle.toml";
+
+/// Default settings read from TOML config files. Keys are named after the long command line
+/// flags they provide defaults for, which take precedence over them.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct Config {
+    pub guess_list: Option<String>,
+    pub answer_list: Option<String>,
+    pub strategy: Option<String>,
+    pub temperature: Option<f64>,
+    pub filter_backend: Option<String>,
+    pub hard_mode: Option<bool>,
+    pub max_guesses: Option<usize>,
+    pub high_contrast: Option<bool>,
+    pub cache_dir: Option<PathBuf>,
+}
+
+impl Config {
+    /// The per-user config file location in the user's config directory, if it can be
+    /// determined.
+    pub fn user_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("crustacean-wordle").join("config.toml"))
+    }
+
+    /// The per-project config file nearest the current directory, if there is one.
+    pub fn project_path() -> Option<PathBuf> {
+        env::current_dir()
+            .ok()?
+            .ancestors()
+            .map(|dir| dir.join(PROJECT_CONFIG_FILE))
+            .find(|path| path.is_file())
+    }
+
+    /// Read the per-user config, overridden by the per-project config. Missing files are
+    /// treated as empty.
+    pub fn load() -> Result<Self, String> {
+        let mut config = Config::default();
+        for path in [Config::user_path(), Config::project_path()]
+            .into_iter()
+            .flatten()
+        {
+            config = config.merge(Config::read(&path)?);
+        }
+
+        Ok(config)
+    }
+
+    /// Read the config file at `path`, or return an empty config if it does not exist.
+    /// Relative paths in the file are resolved against its directory.
+    pub fn read(path: &Path) -> Result<Self, String> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Config::default()),
+            Err(err) => return Err(format!("Could not read config file {:?}: {}", path, err)),
+        };
+
+        let mut config: Config = toml::from_str(&text)
+            .map_err(|err| format!("Invalid config file {:?}: {}", path, err))?;
+
+        let dir = path.parent().unwrap_or_else(|| Path::new(""));
+        let resolve_list = |list: String| {
+            if list.starts_with(BUILTIN_PREFIX) {
+                list
+            } else {
+                dir.join(list).to_string_lossy().into_owned()
+            }
+        };
+        config.guess_list = config.guess_list.map(resolve_list);
+        config.answer_list = config.answer_list.map(resolve_list);
+        config.cache_dir = config.cache_dir.map(|cache_dir| dir.join(cache_dir));
+
+        Ok(config)
+    }
+
+    /// Combine this config with `other`, whose settings take precedence.
+    fn merge(self, other: Config) -> Self {
+        Config {
+            guess_list: other.guess_list.or(self.guess_list),
+            answer_list: other.answer_list.or(self.answer_list),
+            strategy: other.strategy.or(self.strategy),
+            temperature: other.temperature.or(self.temperature),
+            filter_backend: other.filter_backend.or(self.filter_backend),
+            hard_mode: other.hard_mode.or(self.hard_mode),
+            max_guesses: other.max_guesses.or(self.max_guesses),
+            high_contrast: other.high_contrast.or(self.high_contrast),
+            cache_dir: other.cache_dir.or(self.cache_dir),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The temporary directory in which the config file called `name` is written.
+    fn config_dir(name: &str) -> PathBuf {
+        let dir = format!("crustacean-wordle-config-{}-{}", std::process::id(), name);
+        env::temp_dir().join(dir)
+    }
+
+    /// Write `text` to a config file called `name` in a fresh temporary directory, and read it.
+    fn read_config(name: &str, text: &str) -> Result<Config, String> {
+        let dir = config_dir(name);
+        let path = dir.join(name);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&path, text).unwrap();
+        let config = Config::read(&path);
+        fs::remove_dir_all(&dir).unwrap();
+        config
+    }
+
+    #[test]
+    fn test_read() {
+        let config = read_config(
+            "sample.toml",
+            r#"
+            strategy = "bayesian"
+            temperature = 0.5
+            filter-backend = "outcomes"
+            hard-mode = true
+            max-guesses = 8
+            high-contrast = false
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.strategy.as_deref(), Some("bayesian"));
+        assert_eq!(config.temperature, Some(0.5));
+        assert_eq!(config.filter_backend.as_deref(), Some("outcomes"));
+        assert_eq!(config.hard_mode, Some(true));
+        assert_eq!(config.max_guesses, Some(8));
+        assert_eq!(config.high_contrast, Some(false));
+        assert!(config.guess_list.is_none() && config.cache_dir.is_none());
+
+        assert!(read_config("unknown.toml", "hard-mode = true\nhardmode = true\n").is_err());
+    }
+
+    #[test]
+    fn test_read_resolves_paths() {
+        let config = read_config(
+            "paths.toml",
+            r#"
+            guess-list = "lists/guesses.txt"
+            answer-list = "builtin:answers"
+            cache-dir = ".cache"
+            "#,
+        )
+        .unwrap();
+
+        let dir = config_dir("paths.toml");
+        assert_eq!(
+            config.guess_list.map(PathBuf::from),
+            Some(dir.join("lists/guesses.txt"))
+        );
+        assert_eq!(config.answer_list.as_deref(), Some("builtin:answers"));
+        assert_eq!(config.cache_dir, Some(dir.join(".cache")));
+    }
+
+    #[test]
+    fn test_merge() {
+        let user = Config {
+            strategy: Some("bayesian".to_string()),
+            hard_mode: Some(true),
+            max_guesses: Some(8),
+            ..Default::default()
+        };
+        let project = Config {
+            strategy: Some("entropy".to_string()),
+            hard_mode: Some(false),
+            ..Default::default()
+        };
+
+        let config = user.merge(project);
+        assert_eq!(config.strategy.as_deref(), Some("entropy"));
+        assert_eq!(config.hard_mode, Some(false));
+        assert_eq!(config.max_guesses, Some(8));
+    }
+}
@@ -0,0 +1,58 @@
+use chrono::{Local, NaiveDate};
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::words::{HasWords, WordPtr, WordlistPtr};
+
+/// The seed used to order daily answers unless another is configured. Everyone using the same
+/// seed and answer list gets the same word each day.
+pub const DEFAULT_DAILY_SEED: u64 = 0x5EED_CAFE_F00D;
+
+/// The date of daily puzzle #0 (the same as the original Wordle's).
+pub fn first_puzzle_date() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2021, 6, 19).expect("Invalid daily puzzle epoch!")
+}
+
+/// Today's date in the local timezone.
+pub fn today() -> NaiveDate {
+    Local::now().date_naive()
+}
+
+/// The daily puzzle number for the given `date`, or None if it is before the first puzzle.
+pub fn puzzle_number(date: NaiveDate) -> Option<usize> {
+    usize::try_from((date - first_puzzle_date()).num_days()).ok()
+}
+
+/// Deterministically choose the answer for daily puzzle `puzzle_number` from `answerlist`.
+/// The answers are shuffled by `seed`, so that no answer repeats until all have been used.
+pub fn daily_word(answerlist: &WordlistPtr, puzzle_number: usize, seed: u64) -> Option<WordPtr> {
+    let words = answerlist.possible_words();
+    if words.is_empty() {
+        return None;
+    }
+
+    let cycle = (puzzle_number / words.len()) as u64;
+    let mut order: Vec<usize> = (0..words.len()).collect();
+    order.shuffle(&mut ChaCha8Rng::seed_from_u64(seed.wrapping_add(cycle)));
+
+    words.get(order[puzzle_number % words.len()]).cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puzzle_number() {
+        assert_eq!(puzzle_number(first_puzzle_date()), Some(0));
+        assert_eq!(
+            puzzle_number(NaiveDate::from_ymd_opt(2022, 1, 1).unwrap()),
+            Some(196)
+        );
+        assert_eq!(
+            puzzle_number(NaiveDate::from_ymd_opt(2021, 6, 18).unwrap()),
+            None
+        );
+    }
+}
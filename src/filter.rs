@@ -0,0 +1,305 @@
+use rayon::prelude::*;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use crate::{
+    game::{encode_outcome, winning_outcome_code, Guess, OutcomeCode},
+    pattern::Pattern,
+    words::{CanPatternFilter, HasWords, SubWordlist, Word, Wordlist},
+};
+
+/// The number of distinct outcome codes that fit in an `OutcomeTable` entry.
+const OUTCOME_TABLE_CODES: usize = u8::MAX as usize + 1;
+
+/// The version of the format of cached `OutcomeTable` files, to be bumped whenever outcome
+/// codes or their layout change so that stale caches are not read.
+const OUTCOME_TABLE_CACHE_VERSION: u32 = 1;
+
+/// How a strategy narrows down the candidate answers after each guess.
+#[derive(Clone)]
+pub enum FilterBackend {
+    /// Keep the words matching the `Pattern` of letter constraints accumulated over all guesses.
+    Pattern,
+    /// Keep the words that reproduce the outcome of each guess, which is exact by construction.
+    /// Outcomes are looked up in the table if one is given, and computed otherwise.
+    Outcomes(Option<Arc<OutcomeTable>>),
+}
+
+impl FilterBackend {
+    /// The name of this backend, as used on the command line.
+    pub fn name(&self) -> &'static str {
+        match self {
+            FilterBackend::Pattern => "pattern",
+            FilterBackend::Outcomes(None) => "outcomes",
+            FilterBackend::Outcomes(Some(_)) => "outcomes (table)",
+        }
+    }
+
+    /// Narrow `candidates` (drawn from the answer list) down to the words consistent with
+    /// `guess`, where `knowledge` has been accumulated over all guesses up to and including it.
+    pub fn filter(
+        &self,
+        candidates: &dyn CanPatternFilter,
+        guesslist: &Wordlist,
+        knowledge: &Pattern,
+        guess: &Guess,
+    ) -> Arc<SubWordlist> {
+        let table = match self {
+            FilterBackend::Pattern => return candidates.filter_pattern(knowledge),
+            FilterBackend::Outcomes(table) => table,
+        };
+
+        let outcome = encode_outcome(&guess.outcome);
+        // Letters that do not make up a word cannot have been guessed against any candidate.
+        let Some(guess) = guess.word() else {
+            return candidates.filter_indexed(&|_, _| false);
+        };
+        let guess_idx = guesslist.index_of(guess.as_str());
+
+        match (table, guess_idx) {
+            (Some(table), Some(guess_idx)) => {
+                candidates.filter_indexed(&|idx, _| table.code(guess_idx, idx) == outcome)
+            }
+            _ => candidates.filter_indexed(&|_, word| {
+                word.len() == guess.len() && word.outcome_code(&guess) == outcome
+            }),
+        }
+    }
+}
+
+/// The initial state of a 64-bit FNV-1a hash.
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// Continue the 64-bit FNV-1a hash `hash` with `bytes`. Unlike the standard library's hashers,
+/// this is guaranteed to give the same result in every build.
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// The precomputed outcome of every word in a guess list against every word in an answer list.
+pub struct OutcomeTable {
+    codes: Vec<u8>,
+    num_answers: usize,
+}
+
+impl OutcomeTable {
+    /// Compute the table for `guesslist` and `answerlist`, or None if their words are too long
+    /// for outcome codes to fit in a byte.
+    pub fn build(guesslist: &Wordlist, answerlist: &Wordlist) -> Option<Self> {
+        let guesses = guesslist.possible_words();
+        let answers = answerlist.possible_words();
+
+        let fits = |word: &Word| winning_outcome_code(word.len()) < OUTCOME_TABLE_CODES as u32;
+        if !guesses.iter().chain(answers).all(|word| fits(word)) {
+            return None;
+        }
+
+        let codes = guesses
+            .par_iter()
+            .flat_map_iter(|guess| {
+                answers.iter().map(move |answer| {
+                    if answer.len() == guess.len() {
+                        answer.outcome_code(guess) as u8
+                    } else {
+                        u8::MAX
+                    }
+                })
+            })
+            .collect();
+
+        Some(OutcomeTable {
+            codes,
+            num_answers: answers.len(),
+        })
+    }
+
+    /// Read the table for `guesslist` and `answerlist` from `cache_dir` if it has been cached
+    /// there, and otherwise compute it and cache it for next time.
+    pub fn cached(guesslist: &Wordlist, answerlist: &Wordlist, cache_dir: &Path) -> Option<Self> {
+        let path = Self::cache_path(guesslist, answerlist, cache_dir);
+        let num_answers = answerlist.possible_words().len();
+        let num_codes = guesslist.possible_words().len() * num_answers;
+
+        match fs::read(&path) {
+            Ok(codes) if codes.len() == num_codes => Some(OutcomeTable { codes, num_answers }),
+            _ => {
+                let table = Self::build(guesslist, answerlist)?;
+                if let Err(err) = Self::write_cache(&path, &table.codes) {
+                    eprintln!("Could not cache outcome table at {:?}: {}", path, err);
+                }
+                Some(table)
+            }
+        }
+    }
+
+    /// Write `codes` to the cache file at `path` by way of a temporary file, so that other
+    /// processes never read a partly written table.
+    fn write_cache(path: &Path, codes: &[u8]) -> Result<(), std::io::Error> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let mut tmp_name = path.file_name().unwrap_or_default().to_os_string();
+        tmp_name.push(format!(".{}.tmp", std::process::id()));
+        let tmp_path = path.with_file_name(tmp_name);
+
+        let result = fs::write(&tmp_path, codes).and_then(|_| fs::rename(&tmp_path, path));
+        if result.is_err() {
+            let _ = fs::remove_file(&tmp_path);
+        }
+        result
+    }
+
+    /// The file in `cache_dir` caching the table for `guesslist` and `answerlist`, named after
+    /// the cache format version and a hash of their words that is stable across builds.
+    fn cache_path(guesslist: &Wordlist, answerlist: &Wordlist, cache_dir: &Path) -> PathBuf {
+        let mut hash = FNV_OFFSET_BASIS;
+        for list in [guesslist, answerlist] {
+            hash = fnv1a(hash, &(list.possible_words().len() as u64).to_le_bytes());
+            for word in list.possible_words() {
+                // Terminate each word, so that different lists never hash the same bytes.
+                hash = fnv1a(hash, word.as_str().as_bytes());
+                hash = fnv1a(hash, &[0]);
+            }
+        }
+
+        cache_dir.join(format!(
+            "outcomes-v{}-{:016x}.bin",
+            OUTCOME_TABLE_CACHE_VERSION, hash
+        ))
+    }
+
+    /// The outcome of the guess at `guess_idx` in the guess list against the answer at
+    /// `answer_idx` in the answer list.
+    #[inline(always)]
+    pub fn code(&self, guess_idx: usize, answer_idx: usize) -> OutcomeCode {
+        self.codes[guess_idx * self.num_answers + answer_idx] as OutcomeCode
+    }
+
+    /// The number of answers at `answer_indices` giving each outcome for the guess at
+    /// `guess_idx`.
+    pub fn outcome_counts(
+        &self,
+        guess_idx: usize,
+        answer_indices: &[usize],
+    ) -> Vec<(OutcomeCode, usize)> {
+        let mut counts = [0_usize; OUTCOME_TABLE_CODES];
+        for &answer_idx in answer_indices {
+            counts[self.code(guess_idx, answer_idx) as usize] += 1;
+        }
+
+        counts
+            .iter()
+            .enumerate()
+            .filter(|(_, count)| **count > 0)
+            .map(|(code, count)| (code as OutcomeCode, *count))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::words::WordPtr;
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+
+    /// The number of random games filtered by each backend.
+    const NUM_RANDOM_GAMES: usize = 100;
+
+    #[test]
+    fn test_filter_backends() {
+        let data_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("data");
+        let guesslist = Wordlist::init(&data_dir.join("guesslist.txt")).unwrap();
+        let answerlist = Wordlist::init(&data_dir.join("answerlist.txt")).unwrap();
+        let table = OutcomeTable::build(&guesslist, &answerlist).map(Arc::new);
+        assert!(table.is_some());
+
+        let backends = [
+            FilterBackend::Pattern,
+            FilterBackend::Outcomes(None),
+            FilterBackend::Outcomes(table),
+        ];
+
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        for _ in 0..NUM_RANDOM_GAMES {
+            let answer = answerlist.random_word_with(&mut rng).unwrap();
+            let guesses: Vec<Guess> = (0..rng.gen_range(1..=4))
+                .map(|_| {
+                    let guess = guesslist.random_word_with(&mut rng).unwrap();
+                    Guess {
+                        guess: guess.letters().collect(),
+                        outcome: answer.outcome_of_guess(guess),
+                    }
+                })
+                .collect();
+            // The candidates for which every guess would have had the same outcome.
+            let expected: Vec<WordPtr> = answerlist
+                .possible_words()
+                .iter()
+                .filter(|word| {
+                    guesses.iter().all(|guess| {
+                        let word_guessed = guess.word().unwrap();
+                        word.outcome_of_guess(Arc::new(word_guessed)) == guess.outcome
+                    })
+                })
+                .cloned()
+                .collect();
+
+            for backend in &backends {
+                let mut knowledge = Pattern::default();
+                let mut extant: Arc<dyn CanPatternFilter + Send + Sync> = answerlist.clone();
+                for guess in &guesses {
+                    knowledge = knowledge.ingest(guess);
+                    extant = backend.filter(extant.as_ref(), &guesslist, &knowledge, guess);
+                }
+
+                assert_eq!(
+                    extant.possible_words(),
+                    expected.as_slice(),
+                    "{}",
+                    backend.name()
+                );
+            }
+        }
+    }
+
+    /// A small wordlist of the given words, all scored equally.
+    fn wordlist(words: &[&str]) -> Wordlist {
+        let words: Vec<WordPtr> = words
+            .iter()
+            .map(|word| Arc::new(Word::try_from(*word).unwrap()))
+            .collect();
+        let scores = vec![0.0_f64; words.len()];
+        Wordlist::from_words(words, scores)
+    }
+
+    #[test]
+    fn test_cached_outcome_table() {
+        let answerlist = wordlist(&["crane", "slate", "geese", "those"]);
+        let guesslist = wordlist(&["crane", "slate", "geese", "those", "eerie", "fuzzy"]);
+        let cache_dir =
+            std::env::temp_dir().join(format!("crustacean-wordle-{}", std::process::id()));
+
+        let built = OutcomeTable::build(&guesslist, &answerlist).unwrap();
+        let cached = OutcomeTable::cached(&guesslist, &answerlist, &cache_dir).unwrap();
+        let path = OutcomeTable::cache_path(&guesslist, &answerlist, &cache_dir);
+        assert!(path.exists());
+        assert_eq!(fs::read_dir(&cache_dir).unwrap().count(), 1);
+        // The name depends only on the words, so the cache outlives rebuilds of the binary.
+        assert_eq!(
+            path.file_name().unwrap(),
+            "outcomes-v1-9e5423d9afe1e83d.bin"
+        );
+        let reread = OutcomeTable::cached(&guesslist, &answerlist, &cache_dir).unwrap();
+        fs::remove_dir_all(&cache_dir).unwrap();
+
+        assert!(built.codes == cached.codes && built.codes == reread.codes);
+        assert_eq!(reread.num_answers, answerlist.possible_words().len());
+    }
+}
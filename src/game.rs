@@ -1,16 +1,28 @@
 use console::{style, Color, Term};
-use std::{collections::BTreeMap, fmt::Display, iter::Zip, sync::Arc};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs::{self, File},
+    io::{BufReader, BufWriter, ErrorKind, Write},
+    iter::Zip,
+    path::PathBuf,
+    sync::{Arc, OnceLock},
+};
 
 use crate::{
-    strategy::{Strategy, StrategyVerbosity},
-    words::{HasWords, WordPtr, WordlistPtr},
+    strategy::{GuessExplanation, Strategy, StrategyInit, StrategyVerbosity},
+    words::{HasWords, Word, WordPtr, WordlistPtr},
 };
 
-/// The maximum number of allowed guesses per game.
-const ALLOWED_GUESSES_PER_GAME: usize = 6;
+/// The maximum number of allowed guesses per game, unless overridden.
+pub const ALLOWED_GUESSES_PER_GAME: usize = 6;
 
-/// Represents the outcomes of a guess for a single character tile.
-#[derive(Debug, Hash, Copy, Clone, PartialEq, Eq)]
+/// Represents the outcomes of a guess for a single character tile, ordered from least
+/// to most informative.
+#[derive(Debug, Hash, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum TileOutcome {
     Gray,
     Yellow,
@@ -25,10 +37,55 @@ impl TileOutcome {
             TileOutcome::Yellow => Color::Yellow,
         }
     }
+
+    /// This outcome's digit in an `OutcomeCode`.
+    #[inline(always)]
+    fn digit(&self) -> OutcomeCode {
+        match self {
+            TileOutcome::Gray => 0,
+            TileOutcome::Yellow => 1,
+            TileOutcome::Green => 2,
+        }
+    }
+}
+
+/// The outcomes of a guess packed into a base-3 integer, with the first tile's outcome as the
+/// least significant digit.
+pub type OutcomeCode = u32;
+
+/// The base of an `OutcomeCode` (one digit per possible `TileOutcome`).
+pub const OUTCOME_CODE_BASE: OutcomeCode = 3;
+
+/// Pack the tile outcomes of a guess into an `OutcomeCode`.
+pub fn encode_outcome(outcome: &[TileOutcome]) -> OutcomeCode {
+    outcome
+        .iter()
+        .rev()
+        .fold(0, |code, tile| code * OUTCOME_CODE_BASE + tile.digit())
+}
+
+/// Unpack an `OutcomeCode` into the tile outcomes of a guess of length `len`.
+pub fn decode_outcome(mut code: OutcomeCode, len: usize) -> Vec<TileOutcome> {
+    (0..len)
+        .map(|_| {
+            let tile = match code % OUTCOME_CODE_BASE {
+                0 => TileOutcome::Gray,
+                1 => TileOutcome::Yellow,
+                _ => TileOutcome::Green,
+            };
+            code /= OUTCOME_CODE_BASE;
+            tile
+        })
+        .collect()
+}
+
+/// The `OutcomeCode` of a winning (all-green) guess of length `len`.
+pub fn winning_outcome_code(len: usize) -> OutcomeCode {
+    OUTCOME_CODE_BASE.pow(len as u32) - 1
 }
 
 /// Represents a guess and its paired outcome (i.e. gray/green/yellow tiles).
-#[derive(Default)]
+#[derive(Default, Clone, Serialize, Deserialize)]
 pub struct Guess {
     pub guess: Vec<char>,
     pub outcome: Vec<TileOutcome>,
@@ -58,6 +115,214 @@ impl Guess {
     ) -> Zip<std::slice::Iter<'_, char>, std::slice::Iter<'_, TileOutcome>> {
         self.guess.iter().zip(self.outcome.iter())
     }
+    /// The guessed word, or `None` if the guessed letters do not make up a `Word`.
+    pub fn word(&self) -> Option<Word> {
+        Word::try_from(self.guess.iter().collect::<String>()).ok()
+    }
+}
+
+/// A serializable snapshot of a game, from which the game can be resumed with `Game::restore`.
+#[derive(Serialize, Deserialize)]
+pub struct SavedGame {
+    word: String,
+    guesses: Vec<Guess>,
+    strategy: String,
+    #[serde(default)]
+    temperature: Option<f64>,
+    #[serde(default)]
+    filter_backend: Option<String>,
+    debug: bool,
+    #[serde(default)]
+    assisted: bool,
+    #[serde(default)]
+    puzzle_number: Option<usize>,
+    #[serde(default)]
+    seed: Option<u64>,
+    #[serde(default)]
+    hard_mode: bool,
+    #[serde(default = "default_max_guesses")]
+    max_guesses: usize,
+}
+
+fn default_max_guesses() -> usize {
+    ALLOWED_GUESSES_PER_GAME
+}
+
+impl SavedGame {
+    /// Read a saved game from the JSON file at `path`.
+    pub fn read(path: &PathBuf) -> Result<Self, std::io::Error> {
+        let reader = BufReader::new(File::open(path)?);
+        serde_json::from_reader(reader).map_err(std::io::Error::from)
+    }
+
+    /// Write this saved game as JSON to the file at `path` by way of a temporary file, so that
+    /// an interrupted save never leaves a partly written game behind.
+    fn write(&self, path: &PathBuf) -> Result<(), std::io::Error> {
+        let mut tmp_name = path.file_name().unwrap_or_default().to_os_string();
+        tmp_name.push(format!(".{}.tmp", std::process::id()));
+        let tmp_path = path.with_file_name(tmp_name);
+
+        let result = File::create(&tmp_path)
+            .and_then(|file| {
+                let mut writer = BufWriter::new(file);
+                serde_json::to_writer_pretty(&mut writer, self).map_err(std::io::Error::from)?;
+                writer.flush()
+            })
+            .and_then(|_| fs::rename(&tmp_path, path));
+        if result.is_err() {
+            let _ = fs::remove_file(&tmp_path);
+        }
+        result
+    }
+
+    /// The name of the strategy the game was played with.
+    pub fn strategy(&self) -> &str {
+        &self.strategy
+    }
+
+    /// The temperature of the strategy's prior, if it has one.
+    pub fn temperature(&self) -> Option<f64> {
+        self.temperature
+    }
+
+    /// The name of the strategy's filter backend, if it has one.
+    pub fn filter_backend(&self) -> Option<&str> {
+        self.filter_backend.as_deref()
+    }
+
+    /// Whether the game was played with debug output.
+    pub fn debug(&self) -> bool {
+        self.debug
+    }
+}
+
+/// Represents a post-game grade of a single guess, comparing it against the guess
+/// suggested by the strategy at that point.
+pub struct TurnAnalysis {
+    pub guess: String,
+    pub outcome: Vec<TileOutcome>,
+    pub candidates_before: usize,
+    pub candidates_after: usize,
+    /// The information actually gained by the guess, in bits.
+    pub information_gained: f64,
+    pub explanation: GuessExplanation,
+    /// The explanation of the strategy's suggested guess, if it had one.
+    pub best: Option<GuessExplanation>,
+}
+
+impl TurnAnalysis {
+    fn new(
+        guess: &Guess,
+        candidates_before: usize,
+        candidates_after: usize,
+        information_gained: f64,
+        explanation: GuessExplanation,
+        best: Option<GuessExplanation>,
+    ) -> Self {
+        TurnAnalysis {
+            guess: guess.guess.iter().collect(),
+            outcome: guess.outcome.clone(),
+            candidates_before,
+            candidates_after,
+            information_gained,
+            explanation,
+            best,
+        }
+    }
+
+    /// A 0-99 score of how the expected information of the guess compares to that of the
+    /// strategy's suggested guess.
+    pub fn skill(&self) -> u8 {
+        let best_information = self
+            .best
+            .as_ref()
+            .map(|best| best.expected_information)
+            .unwrap_or(0.0_f64);
+
+        if best_information <= 0.0_f64 {
+            // There was nothing left to learn, so the only skillful guess is the answer itself.
+            return if self.outcome.iter().all(|item| item == &TileOutcome::Green) {
+                99
+            } else {
+                0
+            };
+        }
+
+        (99.0_f64 * self.explanation.expected_information / best_information)
+            .round()
+            .clamp(0.0_f64, 99.0_f64) as u8
+    }
+
+    /// A 0-99 score of how lucky the outcome was, i.e. the share of extant words for which the
+    /// guess would have left more candidates than it actually did. This is None if the guess
+    /// could only have had a single outcome.
+    pub fn luck(&self) -> Option<u8> {
+        let buckets = &self.explanation.buckets;
+        if buckets.len() < 2 {
+            return None;
+        }
+
+        let total: usize = buckets.iter().map(|bucket| bucket.words.len()).sum();
+        let actual = buckets
+            .iter()
+            .find(|bucket| bucket.outcome == self.outcome)?
+            .words
+            .len();
+        let worse: usize = buckets
+            .iter()
+            .map(|bucket| bucket.words.len())
+            .filter(|len| *len > actual)
+            .sum();
+
+        Some((99.0_f64 * worse as f64 / total as f64).round() as u8)
+    }
+}
+
+impl Display for TurnAnalysis {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let guess = Guess {
+            guess: self.guess.chars().collect(),
+            outcome: self.outcome.clone(),
+        };
+        writeln!(f, "{}", guess)?;
+        writeln!(
+            f,
+            "    Candidates: {} -> {}",
+            self.candidates_before, self.candidates_after
+        )?;
+
+        match &self.best {
+            Some(best) => {
+                writeln!(
+                    f,
+                    "    Information: {:.2} bits gained ({:.2} expected vs. {:.2} for {})",
+                    self.information_gained,
+                    self.explanation.expected_information,
+                    best.expected_information,
+                    best.guess.get_word().to_ascii_uppercase()
+                )?;
+                writeln!(
+                    f,
+                    "    Strategy suggested: {} (yours ranked {})",
+                    best.guess.get_word().to_ascii_uppercase(),
+                    match self.explanation.rank {
+                        Some(rank) => format!("#{} of {}", rank, self.explanation.num_ranked),
+                        None => "unranked".to_string(),
+                    }
+                )?;
+            }
+            None => writeln!(
+                f,
+                "    Information: {:.2} bits gained ({:.2} expected)",
+                self.information_gained, self.explanation.expected_information
+            )?,
+        }
+
+        match self.luck() {
+            Some(luck) => write!(f, "    Skill: {}/99, Luck: {}/99", self.skill(), luck),
+            None => write!(f, "    Skill: {}/99, Luck: -", self.skill()),
+        }
+    }
 }
 
 /// Represents the game state, including the chosen word, the past guesses, the
@@ -68,11 +333,20 @@ pub struct Game {
     answerlist: WordlistPtr,
     guesslist: WordlistPtr,
     history: Vec<BTreeMap<String, f64>>,
+    /// The strategy's ranking of guesses at each turn, computed once it is first needed.
+    rankings: Vec<OnceLock<Vec<(f64, WordPtr)>>>,
     strategy: Box<dyn Strategy>,
+    strategy_init: Arc<StrategyInit>,
+    verbosity: StrategyVerbosity,
+    assisted: bool,
+    puzzle_number: Option<usize>,
+    seed: Option<u64>,
+    hard_mode: bool,
+    max_guesses: usize,
     debug: bool,
 }
 
-#[derive(PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq)]
 pub enum GameState {
     NotStarted,
     InProgress,
@@ -82,19 +356,29 @@ pub enum GameState {
 }
 
 impl Game {
-    /// Initializes a new Game with the given `wordlist` and strategy initialization function.
+    /// Initializes a new Game with the given wordlists and strategy initialization function.
+    /// The strategy is handed both lists, so that it may guess from the guess list while
+    /// only considering words in the answer list as potential answers.
     pub fn init(
         guesslist: WordlistPtr,
         answerlist: WordlistPtr,
-        strategy_init: &dyn Fn(WordlistPtr) -> Box<dyn Strategy>,
+        strategy_init: Arc<StrategyInit>,
     ) -> Self {
         let mut game = Game {
             word: Arc::default(),
             guesses: vec![],
-            answerlist,
+            answerlist: answerlist.clone(),
             guesslist: guesslist.clone(),
             history: vec![],
-            strategy: strategy_init(guesslist),
+            rankings: vec![],
+            strategy: strategy_init(guesslist, answerlist),
+            strategy_init,
+            verbosity: StrategyVerbosity::Silent,
+            assisted: false,
+            puzzle_number: None,
+            seed: None,
+            hard_mode: false,
+            max_guesses: ALLOWED_GUESSES_PER_GAME,
             debug: false,
         };
         game.push_metrics();
@@ -110,6 +394,21 @@ impl Game {
             .expect("Could not choose random word from empty answer list!");
     }
 
+    /// Choose a word at random from the answer list, reproducibly for the given `seed`.
+    pub fn choose_seeded_random_word(&mut self, seed: u64) {
+        self.word = self
+            .answerlist
+            .random_word_with(&mut ChaCha8Rng::seed_from_u64(seed))
+            .expect("Could not choose random word from empty answer list!");
+        self.seed = Some(seed);
+    }
+
+    /// Choose `word` as the answer to the given daily puzzle.
+    pub fn choose_daily_word(&mut self, puzzle_number: usize, word: WordPtr) {
+        self.word = word;
+        self.puzzle_number = Some(puzzle_number);
+    }
+
     /// Choose the given word (must only be in the guess list, not answer list).
     #[allow(dead_code)]
     pub fn choose_word(&mut self, word: &str) {
@@ -119,25 +418,227 @@ impl Game {
             .expect("Given word is not in guess list!");
     }
 
+    /// Snapshot this game so that it can be saved and resumed later.
+    fn to_saved(&self) -> SavedGame {
+        SavedGame {
+            word: self.word.get_word(),
+            guesses: self
+                .guesses
+                .iter()
+                .map(|guess| guess.as_ref().clone())
+                .collect(),
+            strategy: self.strategy.name().to_string(),
+            temperature: self.strategy.temperature(),
+            filter_backend: self.strategy.filter_backend().map(str::to_string),
+            debug: self.debug,
+            assisted: self.assisted,
+            puzzle_number: self.puzzle_number,
+            seed: self.seed,
+            hard_mode: self.hard_mode,
+            max_guesses: self.max_guesses,
+        }
+    }
+
+    /// Save this game as JSON to the file at `path`, from which it can be resumed later.
+    pub fn save(&self, path: &PathBuf) -> Result<(), std::io::Error> {
+        self.to_saved().write(path)
+    }
+
+    /// Resume the given saved game, rebuilding the strategy by replaying each saved guess.
+    /// The game's strategy must have the settings the saved game was played with.
+    pub fn restore(&mut self, saved: &SavedGame) -> Result<(), std::io::Error> {
+        if saved.strategy != self.strategy.name()
+            || saved
+                .temperature
+                .is_some_and(|t| Some(t) != self.strategy.temperature())
+            || saved
+                .filter_backend
+                .as_deref()
+                .is_some_and(|backend| Some(backend) != self.strategy.filter_backend())
+        {
+            return Err(std::io::Error::new(
+                ErrorKind::InvalidData,
+                "Saved game was played with different strategy settings!",
+            ));
+        }
+        if saved.max_guesses == 0 {
+            return Err(std::io::Error::new(
+                ErrorKind::InvalidData,
+                "Saved game allows no guesses!",
+            ));
+        }
+
+        self.word = self
+            .guesslist
+            .get_word(&saved.word)
+            .ok_or(std::io::Error::new(
+                ErrorKind::InvalidData,
+                "Saved word is not in guess list!",
+            ))?;
+        self.guesses.clear();
+        self.history.clear();
+        self.rankings.clear();
+        self.strategy = self.fresh_strategy();
+        self.push_metrics();
+        self.set_debug(&saved.debug);
+        self.assisted = saved.assisted;
+        self.puzzle_number = saved.puzzle_number;
+        self.seed = saved.seed;
+        self.hard_mode = saved.hard_mode;
+        self.max_guesses = saved.max_guesses;
+
+        for guess in &saved.guesses {
+            let guessed_word: String = guess.guess.iter().collect();
+            let word = self
+                .guesslist
+                .get_word(&guessed_word)
+                .ok_or(std::io::Error::new(
+                    ErrorKind::InvalidData,
+                    "Saved guess is not in guess list!",
+                ))?;
+
+            if self.word.outcome_of_guess(word) != guess.outcome {
+                return Err(std::io::Error::new(
+                    ErrorKind::InvalidData,
+                    "Saved guess outcome does not match the saved word!",
+                ));
+            }
+
+            self.register_guess(Box::new(guess.clone()));
+        }
+
+        Ok(())
+    }
+
     /// Make a given guess.
     pub fn make_guess(&mut self, guess: WordPtr) {
         let outcome = self.word.outcome_of_guess(guess.clone());
         let guess = Box::new(Guess {
-            guess: guess.get_word().chars().collect(),
+            guess: guess.letters().collect(),
             outcome,
         });
+        self.register_guess(guess);
+    }
+
+    /// Rewind the game to just after the first `num_guesses` guesses, undoing all later ones.
+    /// The strategy is rebuilt by replaying the remaining guesses.
+    pub fn rewind(&mut self, num_guesses: usize) {
+        if num_guesses >= self.guesses.len() {
+            return;
+        }
+
+        self.guesses.truncate(num_guesses);
+        self.history.truncate(num_guesses + 1);
+        self.rankings.truncate(num_guesses + 1);
+
+        self.strategy = self.fresh_strategy();
+        for guess in &self.guesses {
+            self.strategy.register_guess(guess);
+        }
+    }
+
+    /// Start over without any guesses or chosen word, so that a new word can be chosen.
+    pub fn reset(&mut self) {
+        self.word = Arc::default();
+        self.guesses.clear();
+        self.history.clear();
+        self.rankings.clear();
+        self.strategy = self.fresh_strategy();
+        self.push_metrics();
+        self.assisted = false;
+        self.puzzle_number = None;
+        self.seed = None;
+    }
+
+    /// Register the given guess with the strategy and record it.
+    fn register_guess(&mut self, guess: Box<Guess>) {
         self.strategy.register_guess(&guess);
         self.guesses.push(guess);
         self.push_metrics();
     }
 
+    /// Record the strategy's metrics for the current turn, and make room for its ranking.
     fn push_metrics(&mut self) {
         self.history.push(self.strategy.metrics());
+        self.rankings.push(OnceLock::new());
+    }
+
+    /// The strategy's ranking of guesses at the current turn.
+    fn current_ranking(&self) -> &[(f64, WordPtr)] {
+        self.rankings
+            .last()
+            .expect("Game has no current turn!")
+            .get_or_init(|| self.strategy.ranked_guesses())
     }
 
-    /// Retrieve next guess from strategy.
+    /// Retrieve next guess from strategy. In hard mode, this is the strategy's best guess
+    /// that uses every hint revealed so far.
     pub fn next_guess(&self) -> Option<WordPtr> {
-        self.strategy.chosen_guess()
+        let ranked = self.current_ranking();
+
+        if self.verbosity == StrategyVerbosity::Debug {
+            for (score, guess) in ranked.iter().take(5) {
+                println!("{} ({})", guess, score);
+            }
+        }
+
+        ranked
+            .iter()
+            .map(|(_, guess)| guess)
+            .find(|guess| self.hard_mode_violation(guess).is_none())
+            .cloned()
+    }
+
+    /// In hard mode, describe how `guess` fails to use the hints revealed so far, if it does:
+    /// green letters must stay in place, and yellow letters must be reused.
+    pub fn hard_mode_violation(&self, guess: &WordPtr) -> Option<String> {
+        self.hard_mode_violation_after(self.guesses.len(), guess)
+    }
+
+    /// Like `hard_mode_violation`, but using only the hints revealed by the first `num_guesses`
+    /// guesses.
+    fn hard_mode_violation_after(&self, num_guesses: usize, guess: &WordPtr) -> Option<String> {
+        if !self.hard_mode {
+            return None;
+        }
+
+        let letters: Vec<char> = guess.letters().collect();
+        for past in &self.guesses[..num_guesses] {
+            for (idx, (letter, outcome)) in past.paired_iter().enumerate() {
+                if outcome == &TileOutcome::Green && letters.get(idx) != Some(letter) {
+                    return Some(format!(
+                        "Letter {} must be {}.",
+                        idx + 1,
+                        letter.to_ascii_uppercase()
+                    ));
+                }
+            }
+
+            for letter in past.guess.iter() {
+                let revealed = past
+                    .paired_iter()
+                    .filter(|(other, outcome)| *other == letter && outcome != &&TileOutcome::Gray)
+                    .count();
+                let used = letters.iter().filter(|other| *other == letter).count();
+                if used < revealed {
+                    return Some(format!(
+                        "Guess must contain {}.",
+                        letter.to_ascii_uppercase()
+                    ));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Explain the given guess under the current strategy.
+    pub fn explain_guess(&self, guess: &WordPtr) -> GuessExplanation {
+        GuessExplanation::new(
+            guess,
+            self.strategy.extant_guesses(),
+            self.current_ranking(),
+        )
     }
 
     /// Pretty-print game state.
@@ -167,6 +668,12 @@ impl Game {
             )
             .as_str(),
         )?;
+
+        if let Some(puzzle_number) = self.puzzle_number {
+            term.write_line(format!("Daily puzzle: #{}", puzzle_number).as_str())?;
+        } else if let Some(seed) = self.seed {
+            term.write_line(format!("Seed: {}", seed).as_str())?;
+        }
         term.move_cursor_down(1)?;
 
         for (idx, guess) in self.guesses.iter().enumerate() {
@@ -200,9 +707,68 @@ impl Game {
         self.strategy.extant_guesses()
     }
 
+    /// Initialize a fresh instance of this game's strategy, without any registered guesses.
+    fn fresh_strategy(&self) -> Box<dyn Strategy> {
+        let mut strategy = (self.strategy_init)(self.guesslist.clone(), self.answerlist.clone());
+        strategy.set_verbosity(self.verbosity);
+        strategy
+    }
+
+    /// Grade each guess made so far against the strategy's suggestion at that point, by
+    /// replaying the guesses with a fresh strategy and comparing against the metrics history.
+    /// Rankings already computed during play are reused. In hard mode, the suggestion is the
+    /// best guess that was allowed at that point.
+    pub fn analyze(&self) -> Result<Vec<TurnAnalysis>, std::io::Error> {
+        let mut strategy = self.fresh_strategy();
+        let mut analyses = vec![];
+
+        for (idx, guess) in self.guesses.iter().enumerate() {
+            let guessed_word: String = guess.guess.iter().collect();
+            let word = self
+                .guesslist
+                .get_word(&guessed_word)
+                .ok_or(std::io::Error::new(
+                    ErrorKind::InvalidData,
+                    "Guessed word is not in guess list!",
+                ))?;
+
+            let extant_words = strategy.extant_guesses();
+            let ranked = self.rankings[idx].get_or_init(|| strategy.ranked_guesses());
+            let explanation = GuessExplanation::new(&word, extant_words, ranked);
+            let best = ranked
+                .iter()
+                .map(|(_, best_guess)| best_guess)
+                .find(|best_guess| self.hard_mode_violation_after(idx, best_guess).is_none())
+                .map(|best_guess| GuessExplanation::new(best_guess, extant_words, ranked));
+
+            let metric = |turn: usize, key: &str| {
+                self.history
+                    .get(turn)
+                    .and_then(|metrics| metrics.get(key))
+                    .cloned()
+                    .unwrap_or(0.0_f64)
+            };
+            let entropy_before = metric(idx, "unweighted_entropy").max(0.0_f64);
+            let entropy_after = metric(idx + 1, "unweighted_entropy").max(0.0_f64);
+
+            analyses.push(TurnAnalysis::new(
+                guess,
+                metric(idx, "extant_guesses") as usize,
+                metric(idx + 1, "extant_guesses") as usize,
+                entropy_before - entropy_after,
+                explanation,
+                best,
+            ));
+
+            strategy.register_guess(guess);
+        }
+
+        Ok(analyses)
+    }
+
     /// Retrieve the current game state.
     pub fn current_state(&self) -> GameState {
-        if self.word.get_word().is_empty() {
+        if self.word.is_empty() {
             return GameState::NotStarted;
         }
 
@@ -217,7 +783,7 @@ impl Game {
                 .all(|item| item == &TileOutcome::Green)
             {
                 GameState::GuesserVictory
-            } else if self.guesses.len() >= ALLOWED_GUESSES_PER_GAME {
+            } else if self.guesses.len() >= self.max_guesses {
                 GameState::GuesserDefeat
             } else {
                 GameState::InProgress
@@ -237,6 +803,7 @@ impl Game {
 
     /// Set strategy verbosity.
     pub fn set_verbosity(&mut self, verbosity: StrategyVerbosity) {
+        self.verbosity = verbosity;
         self.strategy.set_verbosity(verbosity)
     }
 
@@ -250,6 +817,55 @@ impl Game {
         self.guesslist.clone()
     }
 
+    /// The list of words that may be chosen as the answer.
+    pub fn get_answerlist(&self) -> WordlistPtr {
+        self.answerlist.clone()
+    }
+
+    /// Record that the strategy was consulted for a guess in this game.
+    pub fn set_assisted(&mut self) {
+        self.assisted = true;
+    }
+
+    /// Whether the strategy was consulted for a guess in this game.
+    pub fn is_assisted(&self) -> bool {
+        self.assisted
+    }
+
+    /// Retrieve the chosen word, but only once the game is over.
+    pub fn revealed_word(&self) -> Option<WordPtr> {
+        if self.is_over() {
+            Some(self.word.clone())
+        } else {
+            None
+        }
+    }
+
+    /// Retrieve the daily puzzle number, if this is a daily puzzle.
+    pub fn puzzle_number(&self) -> Option<usize> {
+        self.puzzle_number
+    }
+
+    /// Retrieve the maximum number of guesses allowed.
+    pub fn max_guesses(&self) -> usize {
+        self.max_guesses
+    }
+
+    /// Set the maximum number of guesses allowed.
+    pub fn set_max_guesses(&mut self, max_guesses: usize) {
+        self.max_guesses = max_guesses;
+    }
+
+    /// Require every guess to use the hints revealed so far, see `hard_mode_violation`.
+    pub fn set_hard_mode(&mut self, hard_mode: bool) {
+        self.hard_mode = hard_mode;
+    }
+
+    /// Retrieve the guesses made so far.
+    pub fn guesses(&self) -> impl Iterator<Item = &Guess> {
+        self.guesses.iter().map(|guess| guess.as_ref())
+    }
+
     /// Retrieve number of guesses taken.
     pub fn num_guesses(&self) -> usize {
         self.guesses.len()
@@ -288,3 +904,262 @@ impl Display for Game {
         writeln!(f, "{}", divider)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        strategy::EntropyStrategy,
+        words::{Word, Wordlist},
+    };
+
+    /// A small wordlist of the given words, all scored equally.
+    fn wordlist(words: &[&str]) -> WordlistPtr {
+        let words: Vec<WordPtr> = words
+            .iter()
+            .map(|word| Arc::new(Word::try_from(*word).unwrap()))
+            .collect();
+        let scores = vec![0.0_f64; words.len()];
+        Arc::new(Wordlist::from_words(words, scores))
+    }
+
+    /// A game against `answer` over a small fixed wordlist, in which "fuzzy" can be guessed
+    /// but is not a potential answer.
+    fn small_game(answer: &str) -> Game {
+        let answers = ["crane", "crate", "grate", "plate", "slate"];
+        let guesses = [&answers[..], &["fuzzy"]].concat();
+        let mut game = Game::init(
+            wordlist(&guesses),
+            wordlist(&answers),
+            Arc::new(EntropyStrategy::init),
+        );
+        game.choose_word(answer);
+        game
+    }
+
+    fn guess(game: &mut Game, word: &str) {
+        let word = game.get_wordlist().get_word(word).unwrap();
+        game.make_guess(word);
+    }
+
+    #[test]
+    fn test_analyze_skill_and_luck() {
+        let mut game = small_game("slate");
+        guess(&mut game, "fuzzy");
+        guess(&mut game, "plate");
+        guess(&mut game, "slate");
+        assert_eq!(game.current_state(), GameState::GuesserVictory);
+
+        let analyses = game.analyze().unwrap();
+        assert_eq!(analyses.len(), 3);
+
+        // "fuzzy" shares no letters with any candidate, so it cannot tell them apart.
+        assert_eq!(analyses[0].skill(), 0);
+        assert_eq!(analyses[0].luck(), None);
+
+        // Every candidate splits the others evenly, and only "crate" and "grate" would have
+        // left more candidates than "slate" did.
+        assert_eq!(analyses[1].skill(), 99);
+        assert_eq!(analyses[1].luck(), Some(40));
+        assert_eq!(
+            (analyses[1].candidates_before, analyses[1].candidates_after),
+            (5, 1)
+        );
+
+        // Only the answer was left, and it was guessed.
+        assert_eq!(analyses[2].skill(), 99);
+        assert_eq!(analyses[2].luck(), None);
+    }
+
+    #[test]
+    fn test_restore_checks_strategy_settings() {
+        let mut game = small_game("slate");
+        guess(&mut game, "plate");
+        let saved = game.to_saved();
+        assert_eq!(saved.filter_backend.as_deref(), Some("pattern"));
+
+        let mut resumed = small_game("crane");
+        resumed.restore(&saved).unwrap();
+        assert_eq!(resumed.num_guesses(), 1);
+        assert!(resumed.revealed_word().is_none());
+        assert_eq!(resumed.to_saved().word, "slate");
+
+        let mismatched = SavedGame {
+            filter_backend: Some("outcomes".to_string()),
+            ..saved
+        };
+        assert!(small_game("crane").restore(&mismatched).is_err());
+    }
+
+    #[test]
+    fn test_save_and_read_malformed() {
+        let dir = std::env::temp_dir().join(format!(
+            "crustacean-wordle-saved-game-{}",
+            std::process::id()
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("game.json");
+
+        let mut game = small_game("slate");
+        guess(&mut game, "plate");
+        game.save(&path).unwrap();
+        let saved = SavedGame::read(&path).unwrap();
+        assert_eq!(saved.word, "slate");
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+
+        fs::write(&path, "{\"word\": \"slate\"").unwrap();
+        assert!(SavedGame::read(&path).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+
+        let unknown_guess = SavedGame {
+            guesses: vec![Guess {
+                guess: "zzzzz".chars().collect(),
+                outcome: vec![TileOutcome::Gray; 5],
+            }],
+            ..game.to_saved()
+        };
+        assert!(small_game("crane").restore(&unknown_guess).is_err());
+        let wrong_outcome = SavedGame {
+            guesses: vec![Guess {
+                guess: "plate".chars().collect(),
+                outcome: vec![TileOutcome::Green; 5],
+            }],
+            ..game.to_saved()
+        };
+        assert!(small_game("crane").restore(&wrong_outcome).is_err());
+        let no_guesses = SavedGame {
+            max_guesses: 0,
+            ..game.to_saved()
+        };
+        assert!(small_game("crane").restore(&no_guesses).is_err());
+    }
+
+    /// A hard-mode game against "pines", in which every candidate shares "ines" but only
+    /// "twamp", which cannot be the answer, tells all of them apart.
+    fn hard_mode_game() -> Game {
+        let answers = ["mines", "pines", "tines", "wines"];
+        let guesses = [&answers[..], &["lines", "twamp"]].concat();
+        let mut game = Game::init(
+            wordlist(&guesses),
+            wordlist(&answers),
+            Arc::new(EntropyStrategy::init),
+        );
+        game.choose_word("pines");
+        game.set_hard_mode(true);
+        game
+    }
+
+    /// Describe how `word` breaks the rules of hard mode in `game`, if it does.
+    fn violation(game: &Game, word: &str) -> Option<String> {
+        game.hard_mode_violation(&game.get_wordlist().get_word(word).unwrap())
+    }
+
+    #[test]
+    fn test_hard_mode_greens_stay_in_place() {
+        let mut game = small_game("crate");
+        game.set_hard_mode(true);
+        guess(&mut game, "plate");
+
+        assert_eq!(
+            violation(&game, "crane"),
+            Some("Letter 4 must be T.".to_string())
+        );
+        assert_eq!(violation(&game, "grate"), None);
+
+        game.set_hard_mode(false);
+        assert_eq!(violation(&game, "crane"), None);
+    }
+
+    #[test]
+    fn test_hard_mode_yellow_counts() {
+        let words = ["elder", "where", "eerie", "enter", "rebel", "relax"];
+        let mut game = Game::init(
+            wordlist(&words),
+            wordlist(&words),
+            Arc::new(EntropyStrategy::init),
+        );
+        game.choose_word("elder");
+        game.set_hard_mode(true);
+
+        // Both 'e's and the 'r' are yellow, so each must be reused.
+        guess(&mut game, "where");
+        assert_eq!(violation(&game, "rebel"), None);
+        assert_eq!(
+            violation(&game, "relax"),
+            Some("Guess must contain E.".to_string())
+        );
+
+        // One 'e' is now green and one yellow, while the third is gray.
+        guess(&mut game, "eerie");
+        assert_eq!(violation(&game, "enter"), None);
+        assert_eq!(
+            violation(&game, "rebel"),
+            Some("Letter 1 must be E.".to_string())
+        );
+    }
+
+    #[test]
+    fn test_hard_mode_next_guess() {
+        let mut game = hard_mode_game();
+        guess(&mut game, "lines");
+
+        assert_eq!(game.current_ranking()[0].1.get_word(), "twamp");
+        assert!(violation(&game, "twamp").is_some());
+        let next = game.next_guess().unwrap();
+        assert_ne!(next.get_word(), "twamp");
+        assert!(game.hard_mode_violation(&next).is_none());
+
+        game.set_hard_mode(false);
+        assert_eq!(game.next_guess().unwrap().get_word(), "twamp");
+    }
+
+    #[test]
+    fn test_hard_mode_analyze_suggests_allowed_guesses() {
+        let mut game = hard_mode_game();
+        guess(&mut game, "lines");
+        guess(&mut game, "mines");
+        guess(&mut game, "pines");
+
+        let analyses = game.analyze().unwrap();
+        let best: Vec<String> = analyses
+            .iter()
+            .map(|analysis| analysis.best.as_ref().unwrap().guess.get_word())
+            .collect();
+        assert!(!best[1..].contains(&"twamp".to_string()));
+    }
+
+    /// The words guessed so far in `game`.
+    fn guessed_words(game: &Game) -> Vec<String> {
+        game.guesses()
+            .map(|guess| guess.guess.iter().collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_rewind_and_reset() {
+        let mut game = small_game("slate");
+        guess(&mut game, "crane");
+        guess(&mut game, "plate");
+        game.rewind(1);
+
+        let mut replayed = small_game("slate");
+        guess(&mut replayed, "crane");
+
+        assert_eq!(guessed_words(&game), vec!["crane"]);
+        assert_eq!(guessed_words(&game), guessed_words(&replayed));
+        assert_eq!(game.history, replayed.history);
+        assert_eq!(game.extant_guesses().len(), replayed.extant_guesses().len());
+        assert_eq!(game.current_state(), GameState::InProgress);
+
+        // Rewinding past the end leaves the game as it is.
+        game.rewind(5);
+        assert_eq!(game.num_guesses(), 1);
+
+        game.reset();
+        let fresh = small_game("slate");
+        assert_eq!(game.current_state(), GameState::NotStarted);
+        assert_eq!(game.num_guesses(), 0);
+        assert_eq!(game.history, fresh.history);
+        assert_eq!(game.extant_guesses().len(), fresh.extant_guesses().len());
+    }
+}
@@ -0,0 +1,109 @@
+use clap::ValueEnum;
+use console::{style, Color};
+
+use crustacean_wordle::game::{Guess, TileOutcome};
+
+/// The width (in characters) of a rendered key, excluding the space between keys.
+const KEY_WIDTH: usize = 3;
+
+/// The layouts available for the on-screen keyboard.
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum KeyboardLayout {
+    #[default]
+    Qwerty,
+    Azerty,
+    Alphabetical,
+}
+
+impl KeyboardLayout {
+    /// The rows of letters in this layout, from top to bottom.
+    pub fn rows(&self) -> [&'static str; 3] {
+        match self {
+            KeyboardLayout::Qwerty => ["qwertyuiop", "asdfghjkl", "zxcvbnm"],
+            KeyboardLayout::Azerty => ["azertyuiop", "qsdfghjklm", "wxcvbn"],
+            KeyboardLayout::Alphabetical => ["abcdefghi", "jklmnopqr", "stuvwxyz"],
+        }
+    }
+}
+
+/// The best known state of each letter ('a' through 'z') over the given guesses, where green
+/// beats yellow and yellow beats gray. Letters that have not been guessed are None.
+pub fn letter_states<'a>(guesses: impl Iterator<Item = &'a Guess>) -> [Option<TileOutcome>; 26] {
+    let mut states: [Option<TileOutcome>; 26] = [None; 26];
+    for guess in guesses {
+        for (ch, outcome) in guess.paired_iter() {
+            if !ch.is_ascii_lowercase() {
+                continue;
+            }
+
+            let state = &mut states[(*ch as u8 - b'a') as usize];
+            *state = (*state).max(Some(*outcome));
+        }
+    }
+
+    states
+}
+
+/// Render the keyboard in the given `layout`, colouring each key by its letter state.
+pub fn render_keyboard(layout: KeyboardLayout, states: &[Option<TileOutcome>; 26]) -> String {
+    let rows = layout.rows();
+    let max_len = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+
+    let lines: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            let indent = " ".repeat((max_len - row.len()) * (KEY_WIDTH + 1) / 2);
+            let keys: Vec<String> = row
+                .chars()
+                .map(|ch| {
+                    let key = style(format!(" {} ", ch.to_ascii_uppercase())).bold();
+                    match states[(ch as u8 - b'a') as usize] {
+                        Some(outcome) => key.fg(Color::White).bg(outcome.color()).bright(),
+                        None => key.black().on_color256(250),
+                    }
+                    .to_string()
+                })
+                .collect();
+            format!("{}{}", indent, keys.join(" "))
+        })
+        .collect();
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_letter_states() {
+        let guesses = [
+            Guess {
+                guess: vec!['s', 'p', 'e', 'e', 'd'],
+                outcome: vec![
+                    TileOutcome::Gray,
+                    TileOutcome::Gray,
+                    TileOutcome::Yellow,
+                    TileOutcome::Gray,
+                    TileOutcome::Gray,
+                ],
+            },
+            Guess {
+                guess: vec!['c', 'r', 'e', 'p', 't'],
+                outcome: vec![
+                    TileOutcome::Gray,
+                    TileOutcome::Gray,
+                    TileOutcome::Green,
+                    TileOutcome::Gray,
+                    TileOutcome::Yellow,
+                ],
+            },
+        ];
+
+        let states = letter_states(guesses.iter());
+        assert_eq!(states[(b'e' - b'a') as usize], Some(TileOutcome::Green));
+        assert_eq!(states[(b't' - b'a') as usize], Some(TileOutcome::Yellow));
+        assert_eq!(states[(b's' - b'a') as usize], Some(TileOutcome::Gray));
+        assert_eq!(states[(b'a' - b'a') as usize], None);
+    }
+}
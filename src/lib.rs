@@ -0,0 +1,73 @@
+//! Wordle for Rustaceans: the game, its wordlists, and strategies for solving it.
+//!
+//! A `Game` is played over a guess list and an answer list, with a `Strategy` suggesting
+//! guesses. To have the entropy strategy solve a game on its own:
+//!
+//! ```
+//! use std::sync::Arc;
+//! use crustacean_wordle::{game::GameState, EntropyStrategy, Game, Wordlist};
+//!
+//! let entries = [
+//!     ("crane", 500.0),
+//!     ("slate", 400.0),
+//!     ("those", 300.0),
+//!     ("geese", 200.0),
+//!     ("abide", 100.0),
+//! ];
+//! let guesslist = Wordlist::from_entries(entries);
+//! let answerlist = Wordlist::from_entries(entries);
+//!
+//! let mut game = Game::init(guesslist, answerlist, Arc::new(EntropyStrategy::init));
+//! game.choose_word("those");
+//!
+//! while !game.is_over() {
+//!     let guess = game.next_guess().expect("No guess suggested!");
+//!     game.make_guess(guess);
+//! }
+//!
+//! assert_eq!(game.current_state(), GameState::GuesserVictory);
+//! ```
+//!
+//! Lower-level building blocks are available too, such as scoring a guess against an answer
+//! and accumulating what the outcomes reveal about the answer:
+//!
+//! ```
+//! use std::sync::Arc;
+//! use crustacean_wordle::{
+//!     game::{Guess, TileOutcome},
+//!     words::Word,
+//!     Pattern,
+//! };
+//!
+//! let answer = Word::try_from("those").unwrap();
+//! let guess = Arc::new(Word::try_from("geese").unwrap());
+//! let outcome = answer.outcome_of_guess(guess.clone());
+//! assert_eq!(outcome[3], TileOutcome::Green);
+//!
+//! let pattern = Pattern::default().ingest(&Guess {
+//!     guess: guess.letters().collect(),
+//!     outcome,
+//! });
+//! assert_eq!(pattern.exact_count(&'e'), Some(1));
+//! assert!(answer.matches(&pattern));
+//! assert!(!Word::try_from("these").unwrap().matches(&pattern));
+//! ```
+
+mod bitmask;
+mod bitset;
+pub mod builtin;
+pub mod daily;
+pub mod filter;
+pub mod game;
+pub mod pattern;
+pub mod share;
+pub mod strategy;
+pub mod verify;
+pub mod words;
+
+pub use game::Game;
+pub use pattern::Pattern;
+pub use share::{share_grid, ShareTheme};
+pub use strategy::{BayesianStrategy, EntropyStrategy, Strategy};
+pub use verify::{verify_guesses, Verification};
+pub use words::Wordlist;
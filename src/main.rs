@@ -1,32 +1,55 @@
-use clap::Parser;
+use chrono::NaiveDate;
+use clap::{ArgMatches, CommandFactory, FromArgMatches, Parser, ValueEnum, ValueSource};
+use config::Config;
 use console::{style, Term};
-use dialoguer::{theme::ColorfulTheme, Confirm, FuzzySelect};
-use game::{Game, GameState};
+use crustacean_wordle::{
+    builtin::{
+        WordlistSource, BUILTIN_PREFIX, BUILTIN_WORDLISTS, DEFAULT_ANSWER_LIST, DEFAULT_GUESS_LIST,
+    },
+    daily,
+    filter::{FilterBackend, OutcomeTable},
+    game::{GameState, Guess, SavedGame, ALLOWED_GUESSES_PER_GAME},
+    share,
+    strategy::{self, SigmoidPrior, StrategyInit},
+    verify,
+    words::{CanPatternFilter, HasWords, WordPtr, WordlistPtr},
+    BayesianStrategy, EntropyStrategy, Game, Pattern, ShareTheme, Wordlist,
+};
+use dialoguer::{theme::ColorfulTheme, FuzzySelect, Input, Select};
 use indicatif::{ProgressBar, ProgressStyle};
+use keyboard::KeyboardLayout;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
 use rayon::prelude::*;
+use stats::Stats;
 use std::sync::atomic::Ordering;
+use std::sync::Arc;
 use std::time::Duration;
+use std::time::Instant;
 use std::{path::PathBuf, sync::atomic::AtomicU64};
-use strategy::EntropyStrategy;
-use words::{HasWords, Wordlist, WordlistPtr};
 
-mod bitmask;
-mod game;
-mod pattern;
-mod strategy;
-mod words;
+mod config;
+mod keyboard;
+mod stats;
+mod tui;
 
 /// Wordle for Rustaceans.
 #[derive(Parser, Debug)]
 #[clap(author, version, about, long_about = None)]
 struct Args {
-    /// The wordlist of possible guesses that can be made
-    #[clap(short, long, value_parser, value_name = "FILE")]
-    guess_list: PathBuf,
+    /// The wordlist of possible guesses that can be made, either a file or `builtin:NAME`
+    /// [default: builtin:guesses]
+    #[clap(short, long, value_parser, value_name = "SOURCE")]
+    guess_list: Option<WordlistSource>,
 
-    /// The wordlist of possible answers to randomly choose from
-    #[clap(short, long, value_parser, value_name = "FILE")]
-    answer_list: PathBuf,
+    /// The wordlist of possible answers to randomly choose from, either a file or
+    /// `builtin:NAME` [default: builtin:answers]
+    #[clap(short, long, value_parser, value_name = "SOURCE")]
+    answer_list: Option<WordlistSource>,
+
+    /// List the wordlists built into the binary and exit
+    #[clap(long, action = clap::ArgAction::Count)]
+    list_wordlists: u8,
 
     /// Turn debugging information on
     #[clap(short, long, action = clap::ArgAction::Count)]
@@ -35,55 +58,727 @@ struct Args {
     /// Run a benchmark
     #[clap(short, long, action = clap::ArgAction::Count)]
     benchmark: u8,
+
+    /// After each guess in the REPL, check that pattern filtering agrees with outcome replay
+    #[clap(long, action = clap::ArgAction::Count)]
+    verify: u8,
+
+    /// The strategy used to suggest guesses
+    #[clap(short, long, value_enum, default_value_t = StrategyKind::Entropy)]
+    strategy: StrategyKind,
+
+    /// How the entropy strategy narrows down candidate answers after each guess
+    #[clap(long, value_enum, default_value_t = FilterBackendKind::Pattern)]
+    filter_backend: FilterBackendKind,
+
+    /// Benchmark the speed and exactness of each filter backend
+    #[clap(long, action = clap::ArgAction::Count)]
+    benchmark_filters: u8,
+
+    /// Override the temperature of the Bayesian strategy's frequency prior (must be positive)
+    #[clap(short, long, value_parser = parse_temperature)]
+    temperature: Option<f64>,
+
+    /// Fit the Bayesian strategy's frequency prior against the answer list and print it
+    #[clap(long, action = clap::ArgAction::Count)]
+    fit_prior: u8,
+
+    /// Save the game to this file after every guess
+    #[clap(long, value_parser, value_name = "FILE")]
+    save: Option<PathBuf>,
+
+    /// Resume the game saved in this file (and keep saving to it unless `--save` is given)
+    #[clap(long, value_parser, value_name = "FILE")]
+    load: Option<PathBuf>,
+
+    /// The file in which player statistics are kept (defaults to the user data directory)
+    #[clap(long, value_parser, value_name = "FILE")]
+    stats: Option<PathBuf>,
+
+    /// Also write the shareable result grid to this file at the end of the game
+    #[clap(long, value_parser, value_name = "FILE")]
+    share_file: Option<PathBuf>,
+
+    /// Use the colour-blind friendly (orange/blue) palette for the shareable result grid
+    #[clap(long, action = clap::ArgAction::Count, overrides_with = "no-high-contrast")]
+    high_contrast: u8,
+
+    /// Use the standard palette for the shareable result grid, even if configured otherwise
+    #[clap(long, action = clap::ArgAction::Count, overrides_with = "high-contrast")]
+    no_high_contrast: u8,
+
+    /// Play today's daily puzzle, whose answer is the same for everyone using the same seed
+    #[clap(long, action = clap::ArgAction::Count)]
+    daily: u8,
+
+    /// Play the daily puzzle of this date (YYYY-MM-DD) instead of today's
+    #[clap(long, value_parser = parse_date, value_name = "DATE")]
+    date: Option<NaiveDate>,
+
+    /// Play the daily puzzle with this number instead of today's
+    #[clap(long, value_parser, value_name = "NUMBER")]
+    puzzle: Option<usize>,
+
+    /// The seed used to choose daily puzzle answers
+    #[clap(long, value_parser, default_value_t = daily::DEFAULT_DAILY_SEED)]
+    daily_seed: u64,
+
+    /// Seed the choice of a random answer, to reproduce a game (a seed is chosen and shown otherwise)
+    #[clap(long, value_parser)]
+    seed: Option<u64>,
+
+    /// The layout of the on-screen keyboard
+    #[clap(long, value_enum, default_value_t = KeyboardLayout::Qwerty)]
+    keyboard_layout: KeyboardLayout,
+
+    /// How guesses are entered in the REPL
+    #[clap(long, value_enum, default_value_t = InputMode::Typed)]
+    input_mode: InputMode,
+
+    /// Play in a full-screen, keyboard-driven TUI instead of the REPL (which does not save
+    /// games, record statistics or write share files)
+    #[clap(
+        long,
+        action = clap::ArgAction::Count,
+        conflicts_with_all = &["save", "stats", "share-file"]
+    )]
+    tui: u8,
+
+    /// Compute the answers and guesses consistent with the emoji grid in this file ("-" for stdin)
+    #[clap(long, value_parser, value_name = "FILE")]
+    decode_grid: Option<PathBuf>,
+
+    /// Require every guess to keep green letters in place and reuse yellow letters
+    #[clap(long, action = clap::ArgAction::Count, overrides_with = "no-hard-mode")]
+    hard_mode: u8,
+
+    /// Allow any guess, even if hard mode is configured
+    #[clap(long, action = clap::ArgAction::Count, overrides_with = "hard-mode")]
+    no_hard_mode: u8,
+
+    /// The number of guesses allowed per game
+    #[clap(
+        long,
+        value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..),
+        default_value_t = ALLOWED_GUESSES_PER_GAME
+    )]
+    max_guesses: usize,
+
+    /// The directory in which outcome tables are cached (defaults to the user cache directory)
+    #[clap(long, value_parser, value_name = "DIR")]
+    cache_dir: Option<PathBuf>,
+}
+
+/// Check that `temperature` is a usable temperature for a `SigmoidPrior`: zero would make
+/// every weight infinite or NaN, and a negative temperature would favour uncommon words.
+fn check_temperature(temperature: f64) -> Result<f64, String> {
+    if temperature.is_finite() && temperature > 0.0_f64 {
+        Ok(temperature)
+    } else {
+        Err(format!(
+            "temperature must be a positive number, not {}",
+            temperature
+        ))
+    }
+}
+
+/// Parse a `--temperature`, see `check_temperature`.
+fn parse_temperature(s: &str) -> Result<f64, String> {
+    let temperature: f64 = s.parse().map_err(|err| format!("{}", err))?;
+    check_temperature(temperature)
+}
+
+/// Parse a `--date`, which must not be before the first daily puzzle.
+fn parse_date(s: &str) -> Result<NaiveDate, String> {
+    let date: NaiveDate = s.parse().map_err(|err| format!("{}", err))?;
+    match daily::puzzle_number(date) {
+        Some(_) => Ok(date),
+        None => Err(no_daily_puzzle(date)),
+    }
+}
+
+/// The error for a `date` before the first daily puzzle.
+fn no_daily_puzzle(date: NaiveDate) -> String {
+    format!(
+        "there is no daily puzzle on {}, as the first was on {}",
+        date,
+        daily::first_puzzle_date()
+    )
+}
+
+/// Fill in the settings in `config` that were not given on the command line, as recorded in
+/// `matches`. A boolean setting counts as given if either its flag or its `--no-` flag was.
+fn apply_config(args: &mut Args, matches: &ArgMatches, config: Config) -> Result<(), String> {
+    let unset = |id: &str| matches.value_source(id) != Some(ValueSource::CommandLine);
+    let unset_flag = |id: &str| unset(id) && unset(&format!("no-{}", id));
+
+    if let (true, Some(list)) = (unset("guess-list"), config.guess_list) {
+        args.guess_list = Some(list.parse()?);
+    }
+    if let (true, Some(list)) = (unset("answer-list"), config.answer_list) {
+        args.answer_list = Some(list.parse()?);
+    }
+    if let (true, Some(strategy)) = (unset("strategy"), config.strategy) {
+        args.strategy = StrategyKind::from_str(&strategy, true)?;
+    }
+    if let (true, Some(temperature)) = (unset("temperature"), config.temperature) {
+        args.temperature = Some(check_temperature(temperature)?);
+    }
+    if let (true, Some(backend)) = (unset("filter-backend"), config.filter_backend) {
+        args.filter_backend = FilterBackendKind::from_str(&backend, true)?;
+    }
+    if let (true, Some(hard_mode)) = (unset_flag("hard-mode"), config.hard_mode) {
+        args.hard_mode = hard_mode as u8;
+    }
+    if let (true, Some(max_guesses)) = (unset("max-guesses"), config.max_guesses) {
+        if max_guesses == 0 {
+            return Err("max-guesses must be at least 1".to_string());
+        }
+        args.max_guesses = max_guesses;
+    }
+    if let (true, Some(high_contrast)) = (unset_flag("high-contrast"), config.high_contrast) {
+        args.high_contrast = high_contrast as u8;
+    }
+    if let (true, Some(cache_dir)) = (unset("cache-dir"), config.cache_dir) {
+        args.cache_dir = Some(cache_dir);
+    }
+
+    Ok(())
+}
+
+/// The number of nearest spelling suggestions offered for words not in the word list.
+const NUM_SPELLING_SUGGESTIONS: usize = 5;
+
+/// The ways guesses can be entered in the REPL.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum InputMode {
+    /// Type the word, which is validated against the word list
+    Typed,
+    /// Fuzzy-search the word list
+    Fuzzy,
+}
+
+/// The maximum number of consistent answers listed when decoding a grid.
+const DECODE_GRID_MAX_ANSWERS: usize = 20;
+
+/// The available strategies.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum StrategyKind {
+    /// Maximize the expected information gained over the answer list
+    Entropy,
+    /// Minimize the expected number of guesses under a frequency prior over the guess list
+    Bayesian,
+}
+
+/// The available filter backends for the entropy strategy.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum FilterBackendKind {
+    /// Match candidates against accumulated letter constraints
+    Pattern,
+    /// Keep candidates reproducing each outcome, using a precomputed outcome table
+    Outcomes,
+}
+
+/// The number of random games filtered by each backend when benchmarking filter backends.
+const FILTER_BENCHMARK_GAMES: usize = 2000;
+
+/// Switch to the strategy settings that `saved` was played with, warning about any given
+/// differently on the command line, as recorded in `matches`.
+fn apply_saved_settings(
+    args: &mut Args,
+    matches: &ArgMatches,
+    saved: &SavedGame,
+) -> Result<(), String> {
+    let from_cli = |id: &str| matches.value_source(id) == Some(ValueSource::CommandLine);
+
+    let strategy = StrategyKind::from_str(saved.strategy(), true)?;
+    let filter_backend = saved
+        .filter_backend()
+        .map(|backend| FilterBackendKind::from_str(backend, true))
+        .transpose()?;
+    if let Some(temperature) = saved.temperature() {
+        check_temperature(temperature)?;
+    }
+
+    let mut ignored = vec![];
+    if from_cli("strategy") && args.strategy != strategy {
+        ignored.push("--strategy");
+    }
+    if from_cli("temperature")
+        && saved
+            .temperature()
+            .is_some_and(|t| args.temperature != Some(t))
+    {
+        ignored.push("--temperature");
+    }
+    if from_cli("filter-backend") && filter_backend.is_some_and(|kind| args.filter_backend != kind)
+    {
+        ignored.push("--filter-backend");
+    }
+    if !ignored.is_empty() {
+        eprintln!(
+            "Resuming with the saved game's strategy settings, ignoring {}.",
+            ignored.join(", ")
+        );
+    }
+
+    args.strategy = strategy;
+    if let Some(temperature) = saved.temperature() {
+        args.temperature = Some(temperature);
+    }
+    if let Some(filter_backend) = filter_backend {
+        args.filter_backend = filter_backend;
+    }
+    Ok(())
+}
+
+/// Build the strategy initialization function selected by `args`.
+fn strategy_init(
+    args: &Args,
+    guess_list: &WordlistPtr,
+    answer_list: &WordlistPtr,
+) -> Arc<StrategyInit> {
+    match args.strategy {
+        StrategyKind::Entropy => {
+            let cache_dir = args
+                .cache_dir
+                .clone()
+                .or_else(|| dirs::cache_dir().map(|dir| dir.join("crustacean-wordle")));
+            match filter_backend(args.filter_backend, guess_list, answer_list, cache_dir) {
+                FilterBackend::Pattern => Arc::new(EntropyStrategy::init),
+                backend => Arc::new(move |guesslist, answerlist| {
+                    EntropyStrategy::with_backend(guesslist, answerlist, backend.clone())
+                }),
+            }
+        }
+        StrategyKind::Bayesian => {
+            let mut prior = SigmoidPrior::fit(guess_list, answer_list);
+            if let Some(temperature) = args.temperature {
+                prior.temperature = temperature;
+            }
+
+            Arc::new(move |guesslist, _answerlist| BayesianStrategy::with_prior(guesslist, prior))
+        }
+    }
+}
+
+/// Build the filter backend of kind `kind` for `guess_list` and `answer_list`, caching any
+/// outcome table in `cache_dir`.
+fn filter_backend(
+    kind: FilterBackendKind,
+    guess_list: &WordlistPtr,
+    answer_list: &WordlistPtr,
+    cache_dir: Option<PathBuf>,
+) -> FilterBackend {
+    match kind {
+        FilterBackendKind::Pattern => FilterBackend::Pattern,
+        FilterBackendKind::Outcomes => {
+            let table = match cache_dir {
+                Some(cache_dir) => OutcomeTable::cached(guess_list, answer_list, &cache_dir),
+                None => OutcomeTable::build(guess_list, answer_list),
+            };
+            FilterBackend::Outcomes(table.map(Arc::new))
+        }
+    }
+}
+
+/// The actions offered each turn by the REPL.
+const REPL_ACTIONS: [(&str, ReplAction); 5] = [
+    ("Make a guess", ReplAction::Guess),
+    ("Consult strategy", ReplAction::Consult),
+    ("Explain a guess", ReplAction::Explain),
+    ("Undo last guess", ReplAction::Undo),
+    ("Rewind to an earlier turn", ReplAction::Rewind),
+];
+
+#[derive(Clone, Copy)]
+enum ReplAction {
+    Guess,
+    Consult,
+    Explain,
+    Undo,
+    Rewind,
+}
+
+/// Prompt the user to select a word from `word_slice`.
+fn select_word(
+    wordlist: &Wordlist,
+    prompt: &str,
+    input_mode: InputMode,
+) -> Result<WordPtr, std::io::Error> {
+    if input_mode == InputMode::Typed {
+        let input: String = Input::with_theme(&ColorfulTheme::default())
+            .with_prompt(prompt)
+            .validate_with(|input: &String| -> Result<(), String> {
+                let input = input.trim().to_ascii_lowercase();
+                if wordlist.get_word(&input).is_some() {
+                    return Ok(());
+                }
+
+                let suggestions: Vec<String> = wordlist
+                    .suggestions(&input, NUM_SPELLING_SUGGESTIONS)
+                    .iter()
+                    .map(|word| word.get_word())
+                    .collect();
+                Err(format!(
+                    "'{}' is not in the word list. Did you mean: {}?",
+                    input,
+                    suggestions.join(", ")
+                ))
+            })
+            .interact_text()?;
+
+        return wordlist
+            .get_word(&input.trim().to_ascii_lowercase())
+            .ok_or(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "Word is not in the word list!",
+            ));
+    }
+
+    let word_slice = wordlist.get_word_slice();
+    let selection = FuzzySelect::with_theme(&ColorfulTheme::default())
+        .with_prompt(prompt)
+        .with_visible_term_rows(10_usize)
+        .items(word_slice)
+        .report(true)
+        .default(0)
+        .interact()?;
+
+    Ok(word_slice[selection].clone())
+}
+
+/// Pretty-print `game`, followed by the on-screen keyboard in `layout`.
+fn print_game(game: &Game, layout: KeyboardLayout) -> Result<(), std::io::Error> {
+    game.pretty_print()?;
+
+    let term = Term::stdout();
+    term.write_line("")?;
+    term.write_line(&keyboard::render_keyboard(
+        layout,
+        &keyboard::letter_states(game.guesses()),
+    ))
+}
+
+/// The settings of an interactive game in the terminal.
+struct ReplOptions {
+    /// The file the game is saved to after every turn.
+    save_path: Option<PathBuf>,
+    /// The file the game's result is recorded in.
+    stats_path: Option<PathBuf>,
+    /// The file the share grid is written to once the game is over.
+    share_path: Option<PathBuf>,
+    share_theme: ShareTheme,
+    keyboard_layout: KeyboardLayout,
+    input_mode: InputMode,
+    /// Whether to check every guess for mistakes.
+    verify: bool,
 }
 
-fn human_repl(game: &mut Game) -> Result<(), std::io::Error> {
+fn human_repl(game: &mut Game, options: &ReplOptions) -> Result<(), std::io::Error> {
+    let &ReplOptions {
+        ref save_path,
+        ref stats_path,
+        ref share_path,
+        share_theme,
+        keyboard_layout,
+        input_mode,
+        verify,
+    } = options;
     let term = Term::stdout();
     term.set_title("Crustacean Wordle");
 
     term.clear_screen()?;
-    game.pretty_print()?;
+    print_game(game, keyboard_layout)?;
 
     let wordlist = game.get_wordlist();
-    let word_slice = wordlist.get_word_slice();
+    let was_over = game.is_over();
 
     while !game.is_over() {
         term.write_line("")?;
 
-        if Confirm::with_theme(&ColorfulTheme::default())
-            .default(true)
-            .with_prompt("Do you want to make a guess?")
-            .interact()?
-        {
-            let selection = FuzzySelect::with_theme(&ColorfulTheme::default())
-                .with_prompt("What is your guess?")
-                .with_visible_term_rows(10_usize)
-                .items(word_slice)
-                .report(true)
-                .default(0)
-                .interact()?;
-
-            let word = word_slice[selection].clone();
-            game.make_guess(word);
-        } else {
-            term.write_line("Consulting strategy for next guess.")?;
+        let action = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("What do you want to do?")
+            .items(&REPL_ACTIONS.map(|(label, _)| label))
+            .default(0)
+            .interact()?;
+
+        match REPL_ACTIONS[action].1 {
+            ReplAction::Guess => {
+                let word = select_word(&wordlist, "What is your guess?", input_mode)?;
+                if let Some(violation) = game.hard_mode_violation(&word) {
+                    term.write_line(format!("Hard mode: {}", violation).as_str())?;
+                    continue;
+                }
+                game.make_guess(word);
+            }
+            ReplAction::Consult => {
+                term.write_line("Consulting strategy for next guess.")?;
+                game.set_assisted();
+
+                let guess = game
+                    .next_guess()
+                    .ok_or(std::io::Error::other("Could not retrieve guess strategy!"))?;
+                game.make_guess(guess);
+            }
+            ReplAction::Explain => {
+                let word = select_word(&wordlist, "Which word should be explained?", input_mode)?;
+                let explanation = game.explain_guess(&word);
+
+                term.write_line("")?;
+                term.write_line(format!("{}", explanation).as_str())?;
+                continue;
+            }
+            ReplAction::Undo => {
+                if game.num_guesses() == 0 {
+                    term.write_line("There are no guesses to undo.")?;
+                    continue;
+                }
+
+                game.rewind(game.num_guesses() - 1);
+            }
+            ReplAction::Rewind => {
+                let turns: Vec<String> = std::iter::once("Start of the game".to_string())
+                    .chain(
+                        game.guesses()
+                            .enumerate()
+                            .map(|(idx, guess)| format!("#{}: {}", idx + 1, guess)),
+                    )
+                    .collect();
+                let turn = Select::with_theme(&ColorfulTheme::default())
+                    .with_prompt("Rewind to just after which turn?")
+                    .items(&turns)
+                    .default(turns.len() - 1)
+                    .interact()?;
+
+                game.rewind(turn);
+            }
+        }
 
-            let guess = game.next_guess().ok_or(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                "Could not retrieve guess strategy!",
-            ))?;
-            game.make_guess(guess);
+        if let Some(path) = save_path {
+            game.save(path)?;
         }
 
         term.clear_screen()?;
-        game.pretty_print()?;
+        print_game(game, keyboard_layout)?;
+
+        if verify {
+            let guesses: Vec<Guess> = game.guesses().cloned().collect();
+            let verification = verify::verify_guesses(&guesses, &game.get_answerlist());
+            term.write_line("")?;
+            term.write_line(verification.to_string().as_str())?;
+        }
+    }
+
+    term.write_line("")?;
+    term.write_line(share::share_grid(game, share_theme).as_str())?;
+    if let Some(path) = share_path {
+        share::write_share_grid(path, game, share_theme)?;
+    }
+
+    if let (false, Some(path)) = (was_over, stats_path) {
+        let num_guesses = match game.current_state() {
+            GameState::GuesserVictory => Some(game.num_guesses()),
+            _ => None,
+        };
+
+        term.write_line("")?;
+        if let Err(err) = stats::record_game(
+            path,
+            num_guesses,
+            game.is_assisted(),
+            game.puzzle_number(),
+            game.max_guesses(),
+        ) {
+            term.write_line(format!("Could not record statistics: {}", err).as_str())?;
+        }
+    }
+
+    term.write_line("")?;
+    term.write_line(style("Analysis").bold().to_string().as_str())?;
+    term.write_line("Analyzing your guesses...")?;
+    let analyses = game.analyze()?;
+
+    term.clear_last_lines(1)?;
+    for (idx, analysis) in analyses.iter().enumerate() {
+        term.write_line(format!("#{}: {}", idx + 1, analysis).as_str())?;
     }
 
     term.write_line("")?;
     term.write_line("Thanks for playing!")
 }
 
-fn benchmark(answer_list: WordlistPtr, guess_list: WordlistPtr) -> Result<(), std::io::Error> {
+fn decode_grid(
+    path: &PathBuf,
+    guess_list: &WordlistPtr,
+    answer_list: &WordlistPtr,
+) -> Result<(), std::io::Error> {
+    let text = if path.as_os_str() == "-" {
+        std::io::read_to_string(std::io::stdin())?
+    } else {
+        std::fs::read_to_string(path)?
+    };
+
+    let term = Term::stdout();
+    let rows = share::parse_share_grid(&text)?;
+    term.write_line(format!("Decoding grid with {} rows...", rows.len()).as_str())?;
+
+    let solutions = share::solve_share_grid(
+        &rows,
+        guess_list,
+        answer_list,
+        strategy::StrategyVerbosity::PrettyPrint,
+    );
+
+    term.write_line(
+        format!(
+            "{} of {} answers are consistent with the grid.",
+            style(solutions.len()).bold(),
+            answer_list.possible_words().len()
+        )
+        .as_str(),
+    )?;
+
+    for solution in solutions.iter().take(DECODE_GRID_MAX_ANSWERS) {
+        let example: Vec<String> = solution
+            .row_guesses
+            .iter()
+            .filter_map(|guesses| guesses.first())
+            .map(|guess| guess.get_word())
+            .collect();
+        term.write_line(
+            format!(
+                "{}: {} guess sequences, e.g. {}",
+                style(solution.answer.get_word()).bold(),
+                solution.num_sequences(),
+                example.join(" -> ")
+            )
+            .as_str(),
+        )?;
+    }
+
+    if solutions.len() > DECODE_GRID_MAX_ANSWERS {
+        term.write_line(
+            format!(
+                "... and {} more answers",
+                solutions.len() - DECODE_GRID_MAX_ANSWERS
+            )
+            .as_str(),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Compare the speed and exactness of each filter backend, both narrowing down candidates over
+/// random games and ranking guesses after the first guess of the first game.
+fn benchmark_filters(
+    answer_list: &WordlistPtr,
+    guess_list: &WordlistPtr,
+) -> Result<(), std::io::Error> {
+    let term = Term::stdout();
+    term.write_line(
+        style("Benchmarking filter backends")
+            .bold()
+            .to_string()
+            .as_str(),
+    )?;
+
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let games: Vec<Vec<Guess>> = (0..FILTER_BENCHMARK_GAMES)
+        .map(|_| {
+            let answer = answer_list
+                .random_word_with(&mut rng)
+                .expect("Answer list is empty!");
+            (0..rng.gen_range(1..=5))
+                .map(|_| {
+                    let guess = guess_list
+                        .random_word_with(&mut rng)
+                        .expect("Guess list is empty!");
+                    Guess {
+                        guess: guess.letters().collect(),
+                        outcome: answer.outcome_of_guess(guess),
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    let expected: Vec<Vec<WordPtr>> = games
+        .iter()
+        .map(|guesses| verify::replay_guesses(guesses, answer_list))
+        .collect();
+
+    let start = Instant::now();
+    let table = OutcomeTable::build(guess_list, answer_list).map(Arc::new);
+    term.write_line(format!("Built outcome table in {:.2?}.", start.elapsed()).as_str())?;
+    term.write_line("")?;
+
+    let mut backends = vec![FilterBackend::Pattern, FilterBackend::Outcomes(None)];
+    backends.extend(table.map(|table| FilterBackend::Outcomes(Some(table))));
+
+    let mut best_guesses: Vec<Option<WordPtr>> = vec![];
+    for backend in backends {
+        let start = Instant::now();
+        let results: Vec<Arc<dyn CanPatternFilter + Send + Sync>> = games
+            .iter()
+            .map(|guesses| {
+                let mut knowledge = Pattern::default();
+                let mut extant: Arc<dyn CanPatternFilter + Send + Sync> = answer_list.clone();
+                for guess in guesses {
+                    knowledge = knowledge.ingest(guess);
+                    extant = backend.filter(extant.as_ref(), guess_list, &knowledge, guess);
+                }
+                extant
+            })
+            .collect();
+        let filter_time = start.elapsed();
+
+        let num_exact = results
+            .iter()
+            .zip(&expected)
+            .filter(|(result, expected)| result.possible_words() == expected.as_slice())
+            .count();
+
+        let mut strategy =
+            EntropyStrategy::with_backend(guess_list.clone(), answer_list.clone(), backend.clone());
+        strategy.register_guess(&games[0][0]);
+        let start = Instant::now();
+        let best_guess = strategy.chosen_guess();
+        let ranking_time = start.elapsed();
+
+        term.write_line(
+            format!(
+                "{:<18} filtering: {:>10.2?} ({}/{} exact)  ranking: {:>10.2?} (best guess: {})",
+                backend.name(),
+                filter_time,
+                num_exact,
+                games.len(),
+                ranking_time,
+                best_guess.as_ref().map_or("-", |guess| guess.as_str()),
+            )
+            .as_str(),
+        )?;
+        best_guesses.push(best_guess);
+    }
+
+    if best_guesses.windows(2).any(|pair| pair[0] != pair[1]) {
+        term.write_line("")?;
+        term.write_line(
+            style("Backends disagree on the best guess!")
+                .red()
+                .to_string()
+                .as_str(),
+        )?;
+    }
+
+    Ok(())
+}
+
+fn benchmark(
+    answer_list: WordlistPtr,
+    guess_list: WordlistPtr,
+    strategy_init: Arc<StrategyInit>,
+) -> Result<(), std::io::Error> {
     let term = Term::stdout();
     term.set_title("Crustacean Wordle");
 
@@ -97,14 +792,13 @@ fn benchmark(answer_list: WordlistPtr, guess_list: WordlistPtr) -> Result<(), st
     let mut game = Game::init(
         guess_list.clone(),
         answer_list.clone(),
-        &EntropyStrategy::init,
+        strategy_init.clone(),
     );
     game.set_verbosity(strategy::StrategyVerbosity::PrettyPrint);
 
-    let first_guess = game.next_guess().ok_or(std::io::Error::new(
-        std::io::ErrorKind::Other,
-        "Could not compute first guess",
-    ))?;
+    let first_guess = game
+        .next_guess()
+        .ok_or(std::io::Error::other("Could not compute first guess"))?;
 
     // Benchmark possible answers in parallel
     term.write_line("")?;
@@ -130,10 +824,10 @@ fn benchmark(answer_list: WordlistPtr, guess_list: WordlistPtr) -> Result<(), st
         let mut game = Game::init(
             guess_list.clone(),
             answer_list.clone(),
-            &EntropyStrategy::init,
+            strategy_init.clone(),
         );
         game.set_verbosity(strategy::StrategyVerbosity::Silent);
-        game.choose_word(&word.get_word());
+        game.choose_word(word.as_str());
         game.make_guess(first_guess.clone());
 
         while !game.is_over() {
@@ -167,18 +861,119 @@ fn benchmark(answer_list: WordlistPtr, guess_list: WordlistPtr) -> Result<(), st
     )
 }
 
+/// Print the name and description of each wordlist built into the binary.
+fn list_wordlists() {
+    if BUILTIN_WORDLISTS.is_empty() {
+        println!("No wordlists are built into this binary.");
+    }
+    for list in BUILTIN_WORDLISTS {
+        println!("{}{}: {}", BUILTIN_PREFIX, list.name, list.description);
+    }
+}
+
+/// Load the wordlist from `source`, or else the built-in list called `default`, exiting with a
+/// usage error naming `flag` if there is no such list.
+fn load_wordlist(source: &Option<WordlistSource>, default: &str, flag: &str) -> WordlistPtr {
+    match source.clone().or_else(|| WordlistSource::builtin(default)) {
+        Some(source) => source.load().unwrap_or_else(|err| {
+            Args::command()
+                .error(
+                    clap::ErrorKind::InvalidValue,
+                    format!("Could not load {}: {}", flag, err),
+                )
+                .exit()
+        }),
+        None => Args::command()
+            .error(
+                clap::ErrorKind::MissingRequiredArgument,
+                format!(
+                    "{} is required, as this binary has no built-in wordlists",
+                    flag
+                ),
+            )
+            .exit(),
+    }
+}
+
 fn main() {
-    let args = Args::parse();
-    let answer_list = Wordlist::init(&args.answer_list);
-    let guess_list = Wordlist::init(&args.guess_list);
+    let matches = Args::command().get_matches();
+    let mut args = Args::from_arg_matches(&matches).unwrap_or_else(|err| err.exit());
+    if let Err(err) = Config::load().and_then(|config| {
+        apply_config(&mut args, &matches, config).map_err(|err| format!("In config: {}", err))
+    }) {
+        Args::command()
+            .error(clap::ErrorKind::InvalidValue, err)
+            .exit();
+    }
+
+    if args.list_wordlists > 0 {
+        list_wordlists();
+        return;
+    }
 
-    if args.benchmark != 0 {
-        benchmark(answer_list, guess_list).unwrap();
+    let answer_list = load_wordlist(&args.answer_list, DEFAULT_ANSWER_LIST, "--answer-list");
+    let guess_list = load_wordlist(&args.guess_list, DEFAULT_GUESS_LIST, "--guess-list");
+
+    let saved = args.load.clone().map(|path| {
+        SavedGame::read(&path)
+            .map_err(|err| err.to_string())
+            .and_then(|saved| apply_saved_settings(&mut args, &matches, &saved).map(|_| saved))
+            .unwrap_or_else(|err| {
+                Args::command()
+                    .error(
+                        clap::ErrorKind::InvalidValue,
+                        format!("Could not load saved game {}: {}", path.display(), err),
+                    )
+                    .exit()
+            })
+    });
+
+    let strategy_init = strategy_init(&args, &guess_list, &answer_list);
+
+    if args.fit_prior != 0 {
+        let prior = SigmoidPrior::fit(&guess_list, &answer_list);
+        println!(
+            "Fitted prior: center = {}, temperature = {}",
+            prior.center, prior.temperature
+        );
+    } else if let Some(path) = &args.decode_grid {
+        decode_grid(path, &guess_list, &answer_list).unwrap();
+    } else if args.benchmark_filters != 0 {
+        benchmark_filters(&answer_list, &guess_list).unwrap();
+    } else if args.benchmark != 0 {
+        benchmark(answer_list, guess_list, strategy_init).unwrap();
     } else {
-        let mut game = Game::init(guess_list, answer_list, &EntropyStrategy::init);
-        game.choose_random_word();
+        let mut game = Game::init(guess_list, answer_list, strategy_init);
+        game.set_hard_mode(args.hard_mode != 0);
+        game.set_max_guesses(args.max_guesses);
+        match &saved {
+            Some(saved) => game.restore(saved).unwrap_or_else(|err| {
+                Args::command()
+                    .error(
+                        clap::ErrorKind::InvalidValue,
+                        format!("Could not restore saved game: {}", err),
+                    )
+                    .exit()
+            }),
+            None if args.daily != 0 || args.date.is_some() || args.puzzle.is_some() => {
+                let date = args.date.unwrap_or_else(daily::today);
+                let puzzle_number = args
+                    .puzzle
+                    .or_else(|| daily::puzzle_number(date))
+                    .unwrap_or_else(|| {
+                        Args::command()
+                            .error(clap::ErrorKind::InvalidValue, no_daily_puzzle(date))
+                            .exit()
+                    });
+                let word =
+                    daily::daily_word(&game.get_answerlist(), puzzle_number, args.daily_seed)
+                        .expect("Could not choose daily word from empty answer list!");
+                game.choose_daily_word(puzzle_number, word);
+            }
+            None => game.choose_seeded_random_word(args.seed.unwrap_or_else(rand::random)),
+        }
 
-        if args.debug != 0 {
+        if args.debug != 0 || saved.as_ref().is_some_and(|saved| saved.debug()) {
             game.set_debug(&true);
             game.set_verbosity(strategy::StrategyVerbosity::Debug);
         } else {
@@ -186,7 +981,24 @@ fn main() {
             game.set_verbosity(strategy::StrategyVerbosity::PrettyPrint);
         }
 
-        human_repl(&mut game).unwrap();
+        if args.tui != 0 {
+            tui::run_tui(&mut game, args.keyboard_layout).unwrap();
+        } else {
+            let options = ReplOptions {
+                save_path: args.save.clone().or_else(|| args.load.clone()),
+                stats_path: args.stats.clone().or_else(Stats::default_path),
+                share_path: args.share_file.clone(),
+                share_theme: if args.high_contrast != 0 {
+                    ShareTheme::HighContrast
+                } else {
+                    ShareTheme::Standard
+                },
+                keyboard_layout: args.keyboard_layout,
+                input_mode: args.input_mode,
+                verify: args.verify > 0,
+            };
+            human_repl(&mut game, &options).unwrap();
+        }
 
         if args.debug != 0 {
             let term = Term::stdout();
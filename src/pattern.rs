@@ -1,97 +1,158 @@
 use crate::bitmask::*;
 use crate::game::{Guess, TileOutcome};
-use std::collections::HashMap;
-
-/// For a specific word character position, this object records whether or not
-/// the position is known to contain a specific character or known to not
-/// contain a set of characters.
-#[derive(Debug, Clone, PartialEq)]
-pub enum PlaceConstraint {
-    IsChar(char),
-    IsNotChars(LetterBitmask),
-}
+use crate::words::MAX_WORD_LENGTH;
+use std::fmt::Display;
 
 /// Stores the accumulation of knowledge gained over a Wordle game, specifically
-/// the characters that do not exist in the word and the positional character
-/// constraints.
-#[derive(Default)]
+/// the letters that may appear at each position and the minimum and maximum
+/// number of times each letter may occur.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Pattern {
-    pub disallowed: LetterBitmask,
-    pub must_contain: HashMap<char, usize>,
-    pub constraints: HashMap<usize, PlaceConstraint>,
+    /// The letters that may appear at each position.
+    pub allowed: [LetterBitmask; MAX_WORD_LENGTH],
+    /// The minimum number of occurrences of each letter, indexed by alphabet position.
+    pub min_counts: [u8; ALPHABET_SIZE],
+    /// The maximum number of occurrences of each letter, indexed by alphabet position.
+    pub max_counts: [u8; ALPHABET_SIZE],
+}
+
+impl Default for Pattern {
+    fn default() -> Self {
+        Pattern {
+            allowed: [ALL_LETTERS; MAX_WORD_LENGTH],
+            min_counts: [0; ALPHABET_SIZE],
+            max_counts: [MAX_WORD_LENGTH as u8; ALPHABET_SIZE],
+        }
+    }
 }
 
 impl Pattern {
     /// Update the knowledge contained within this `Pattern` with the outcome
     /// of `guess`.
     pub fn ingest(&self, guess: &Guess) -> Self {
-        let mut disallowed = self.disallowed.clone();
-        let mut must_contain = self.must_contain.clone();
-
-        let mut constraints: HashMap<usize, PlaceConstraint> = HashMap::new();
-        constraints.reserve(self.constraints.len());
-        let constraints_kvs = self
-            .constraints
-            .iter()
-            .map(|(a, b)| (a.to_owned(), b.to_owned()));
-        constraints.extend(constraints_kvs);
+        let mut pattern = *self;
 
-        let mut count_map: HashMap<&char, usize> = HashMap::new(); // store the min count of characters that appear in yellow at least once
-        let mut to_disallow: Vec<&char> = Vec::new(); // what we will consider banning entirely (depends on if seen in string)
+        // The number of yellow or green tiles for each letter, which is a lower bound on its
+        // count, and an exact count if any tile of that letter is gray.
+        let mut counts = [0_u8; ALPHABET_SIZE];
+        let mut grays: LetterBitmask = 0;
 
         for (idx, (ch, outcome)) in guess.paired_iter().enumerate() {
             let mask = LetterBitmask::char_bitmask(ch);
             match outcome {
-                TileOutcome::Gray | TileOutcome::Yellow => {
-                    if outcome == &TileOutcome::Gray {
-                        to_disallow.push(ch);
-                    } else {
-                        *count_map.entry(ch).or_insert(0) += 1;
-                    }
-
-                    if disallowed & mask == 0 {
-                        if let Some(cons) = constraints.get_mut(&idx) {
-                            match cons {
-                                PlaceConstraint::IsChar(_) => {}
-                                PlaceConstraint::IsNotChars(chars) => {
-                                    *chars |= mask;
-                                }
-                            }
-                        } else {
-                            constraints.insert(idx, PlaceConstraint::IsNotChars(mask));
-                        }
-                    }
-                }
                 TileOutcome::Green => {
-                    *count_map.entry(ch).or_insert(0) += 1;
-                    constraints.insert(idx, PlaceConstraint::IsChar(ch.to_owned()));
+                    pattern.allowed[idx] = mask;
+                    counts[letter_index(mask)] += 1;
+                }
+                TileOutcome::Yellow => {
+                    pattern.allowed[idx] &= !mask;
+                    counts[letter_index(mask)] += 1;
+                }
+                TileOutcome::Gray => {
+                    pattern.allowed[idx] &= !mask;
+                    grays |= mask;
                 }
             }
         }
 
-        for (ch, count) in count_map.iter() {
-            let entry = must_contain.entry(**ch).or_insert(*count);
-            *entry = (*entry).max(*count);
-        }
+        for (letter, count) in counts.iter().enumerate() {
+            pattern.min_counts[letter] = pattern.min_counts[letter].max(*count);
+            if grays & (1 << letter) != 0 {
+                pattern.max_counts[letter] = pattern.max_counts[letter].min(*count);
+            }
 
-        for ch in to_disallow {
-            let mask = LetterBitmask::char_bitmask(ch);
-            if !count_map.contains_key(ch) && disallowed & mask == 0 {
-                disallowed |= mask;
+            if pattern.max_counts[letter] == 0 {
+                for allowed in pattern.allowed.iter_mut() {
+                    *allowed &= !(1 << letter);
+                }
             }
         }
 
-        Pattern {
-            disallowed,
-            must_contain,
-            constraints,
+        pattern
+    }
+
+    /// The minimum number of occurrences of letter `ch`.
+    pub fn min_count(&self, ch: &char) -> usize {
+        self.min_counts[letter_index(LetterBitmask::char_bitmask(ch))] as usize
+    }
+
+    /// The exact number of occurrences of letter `ch`, if it is known. This is the case once a
+    /// tile of the letter has been gray, which caps its count at the number of its yellow and
+    /// green tiles in the same guess.
+    pub fn exact_count(&self, ch: &char) -> Option<usize> {
+        let letter = letter_index(LetterBitmask::char_bitmask(ch));
+        if self.min_counts[letter] == self.max_counts[letter] {
+            Some(self.min_counts[letter] as usize)
+        } else {
+            None
         }
     }
 }
 
+/// The index in the alphabet of the single letter in `mask`.
+#[inline(always)]
+fn letter_index(mask: LetterBitmask) -> usize {
+    mask.trailing_zeros() as usize
+}
+
+/// The letters in `mask`, in alphabetical order.
+fn mask_letters(mask: LetterBitmask) -> String {
+    ('a'..='z')
+        .filter(|ch| mask & LetterBitmask::char_bitmask(ch) != 0)
+        .collect()
+}
+
+impl Display for Pattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let disallowed = self
+            .max_counts
+            .iter()
+            .enumerate()
+            .filter(|(_, max)| **max == 0)
+            .fold(0, |mask, (letter, _)| mask | (1 << letter));
+        writeln!(f, "Disallowed characters: {}", mask_letters(disallowed))?;
+
+        let counts: Vec<String> = ('a'..='z')
+            .filter_map(|ch| match self.exact_count(&ch) {
+                Some(0) => None,
+                Some(count) => Some(format!("{}={}", ch, count)),
+                None if self.min_count(&ch) > 0 => Some(format!("{}>={}", ch, self.min_count(&ch))),
+                None => None,
+            })
+            .collect();
+        writeln!(f, "Letter counts: {}", counts.join(" "))?;
+
+        let constraints: Vec<String> = self
+            .allowed
+            .iter()
+            .enumerate()
+            .filter(|(_, allowed)| **allowed != ALL_LETTERS & !disallowed)
+            .map(|(idx, allowed)| {
+                if allowed.count_ones() == 1 {
+                    format!("{}={}", idx + 1, mask_letters(*allowed))
+                } else {
+                    format!(
+                        "{}!={}",
+                        idx + 1,
+                        mask_letters(!allowed & ALL_LETTERS & !disallowed)
+                    )
+                }
+            })
+            .collect();
+        writeln!(f, "Constraints: {}", constraints.join(" "))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::words::Word;
+    use std::sync::Arc;
+
+    /// The bitmask of all letters in `letters`.
+    fn mask(letters: &str) -> LetterBitmask {
+        letters.chars().collect::<Vec<char>>().to_letter_bitmask()
+    }
 
     #[test]
     fn test_ingest() {
@@ -107,49 +168,29 @@ mod tests {
             ],
         });
 
-        assert_eq!(
-            pattern1.disallowed,
-            LetterBitmask::compute_bitmask(vec!['r', 'a', 's', 'e'].iter())
-        );
-        assert_eq!(
-            pattern1.must_contain,
-            HashMap::from_iter(vec![('t', 1_usize)])
-        );
-        assert_eq!(
-            pattern1.constraints,
-            HashMap::from_iter(vec![
-                (
-                    0,
-                    PlaceConstraint::IsNotChars(vec!['t'].to_letter_bitmask())
-                ),
-                (
-                    1,
-                    PlaceConstraint::IsNotChars(vec!['a'].to_letter_bitmask())
-                ),
-                (
-                    2,
-                    PlaceConstraint::IsNotChars(vec!['r'].to_letter_bitmask())
-                ),
-                (
-                    3,
-                    PlaceConstraint::IsNotChars(vec!['e'].to_letter_bitmask())
-                ),
-                (
-                    4,
-                    PlaceConstraint::IsNotChars(vec!['s'].to_letter_bitmask())
-                ),
-            ])
-        );
-
-        let pattern = Pattern {
-            disallowed: LetterBitmask::compute_bitmask(vec!['a', 'b', 'c'].iter()),
-            must_contain: HashMap::from_iter(vec![('e', 1_usize)]),
-            constraints: HashMap::from_iter(vec![
-                (0, PlaceConstraint::IsChar('d')),
-                (1, PlaceConstraint::IsChar('e')),
-                (2, PlaceConstraint::IsChar('d')),
-            ]),
-        };
+        let remaining = ALL_LETTERS & !mask("ares");
+        assert_eq!(pattern1.allowed[0], remaining & !mask("t"));
+        assert!(pattern1.allowed[1..]
+            .iter()
+            .all(|allowed| *allowed == remaining));
+        assert_eq!(pattern1.min_counts[19], 1);
+        for letter in "ares".chars() {
+            assert_eq!(pattern1.max_counts[letter as usize - 'a' as usize], 0);
+        }
+
+        let mut pattern = Pattern::default();
+        pattern.allowed[0] = mask("d");
+        pattern.allowed[1] = mask("e");
+        pattern.allowed[2] = mask("d");
+        for allowed in pattern.allowed[3..].iter_mut() {
+            *allowed &= !mask("abc");
+        }
+        for letter in "abc".chars() {
+            pattern.max_counts[letter as usize - 'a' as usize] = 0;
+        }
+        pattern.min_counts[3] = 2;
+        pattern.min_counts[4] = 1;
+        pattern.min_counts[7] = 1;
 
         let pattern2 = pattern.ingest(&Guess {
             guess: vec!['d', 'e', 'd', 'e', 'f', 'e'],
@@ -163,33 +204,90 @@ mod tests {
             ],
         });
 
-        assert_eq!(
-            pattern2.disallowed,
-            LetterBitmask::compute_bitmask(vec!['a', 'b', 'c'].iter())
-        );
-        assert_eq!(
-            pattern2.must_contain,
-            HashMap::from_iter(vec![('f', 1_usize), ('d', 2_usize), ('e', 2_usize)])
-        );
-        assert_eq!(
-            pattern2.constraints,
-            HashMap::from_iter(vec![
-                (0, PlaceConstraint::IsChar('d')),
-                (1, PlaceConstraint::IsChar('e')),
-                (2, PlaceConstraint::IsChar('d')),
-                (
-                    3,
-                    PlaceConstraint::IsNotChars(vec!['e'].to_letter_bitmask())
-                ),
-                (
-                    4,
-                    PlaceConstraint::IsNotChars(vec!['f'].to_letter_bitmask())
-                ),
-                (
-                    5,
-                    PlaceConstraint::IsNotChars(vec!['e'].to_letter_bitmask())
-                ),
-            ])
-        );
+        let remaining = ALL_LETTERS & !mask("abc");
+        assert_eq!(pattern2.allowed[..3], [mask("d"), mask("e"), mask("d")]);
+        assert_eq!(pattern2.allowed[3], remaining & !mask("e"));
+        assert_eq!(pattern2.allowed[4], remaining & !mask("f"));
+        assert_eq!(pattern2.allowed[5], remaining & !mask("e"));
+        // The green and yellow 'e's raise its minimum, and the gray one caps it there.
+        assert_eq!((pattern2.min_counts[4], pattern2.max_counts[4]), (2, 2));
+        assert_eq!(pattern2.min_counts[5], 1);
+        // Minimums not raised by the guess are carried over, as are disallowed letters.
+        assert_eq!(pattern2.min_counts[3], 2);
+        assert_eq!(pattern2.min_counts[7], 1);
+        for letter in "abc".chars() {
+            assert_eq!(pattern2.max_counts[letter as usize - 'a' as usize], 0);
+        }
+
+        let pattern3 = Pattern::default().ingest(&Guess {
+            guess: vec!['g', 'e', 'e', 's', 'e'],
+            outcome: vec![
+                TileOutcome::Gray,
+                TileOutcome::Gray,
+                TileOutcome::Gray,
+                TileOutcome::Green,
+                TileOutcome::Green,
+            ],
+        });
+
+        // A gray duplicate caps the count of 'e' at the number of colored 'e' tiles.
+        assert_eq!((pattern3.min_counts[4], pattern3.max_counts[4]), (1, 1));
+        assert_eq!(pattern3.allowed[1] & mask("eg"), 0);
+        assert_eq!(pattern3.allowed[0] & mask("eg"), mask("e"));
+    }
+
+    /// The pattern learned from guessing each of `guesses` against `answer`.
+    fn pattern_for(answer: &str, guesses: &[&str]) -> Pattern {
+        let answer = Word::try_from(answer).unwrap();
+        guesses.iter().fold(Pattern::default(), |pattern, guess| {
+            let guess = Arc::new(Word::try_from(*guess).unwrap());
+            pattern.ingest(&Guess {
+                guess: guess.letters().collect(),
+                outcome: answer.outcome_of_guess(guess.clone()),
+            })
+        })
+    }
+
+    #[test]
+    fn test_exact_counts() {
+        // One yellow and one gray 'e': exactly one 'e', though not at either guessed position.
+        let pattern = pattern_for("abide", &["speed"]);
+        assert_eq!(pattern.exact_count(&'e'), Some(1));
+        assert_eq!(pattern.exact_count(&'d'), None);
+        assert_eq!(pattern.min_count(&'d'), 1);
+        assert_eq!(pattern.exact_count(&'s'), Some(0));
+        assert!(Word::try_from("abide").unwrap().matches(&pattern));
+        assert!(!Word::try_from("eerie").unwrap().matches(&pattern));
+        assert!(!Word::try_from("elide").unwrap().matches(&pattern));
+
+        // One green and two gray 'e's: exactly one 'e', at the green position.
+        let pattern = pattern_for("those", &["geese"]);
+        assert_eq!(pattern.exact_count(&'e'), Some(1));
+        assert!(Word::try_from("those").unwrap().matches(&pattern));
+        assert!(!Word::try_from("ethse").unwrap().matches(&pattern));
+
+        // Two yellow 'e's without a gray one: at least two, with no upper bound.
+        let pattern = pattern_for("geese", &["ester"]);
+        assert_eq!(pattern.exact_count(&'e'), None);
+        assert_eq!(pattern.min_count(&'e'), 2);
+        // Two yellow 'e's and a green one, still without a gray one: at least three.
+        let pattern = pattern_for("geese", &["emcee"]);
+        assert_eq!(pattern.exact_count(&'e'), None);
+        assert_eq!(pattern.min_count(&'e'), 3);
+
+        // Three green 'e's and two gray ones: exactly three.
+        let pattern = pattern_for("geese", &["eeeee"]);
+        assert_eq!(pattern.exact_count(&'e'), Some(3));
+        assert!(Word::try_from("geese").unwrap().matches(&pattern));
+        assert!(!Word::try_from("eeese").unwrap().matches(&pattern));
+
+        // A minimum from one guess is capped by a gray duplicate in a later guess.
+        let pattern = pattern_for("elder", &["where"]);
+        assert_eq!(pattern.min_count(&'e'), 2);
+        assert_eq!(pattern.exact_count(&'e'), None);
+        let pattern = pattern_for("elder", &["where", "eerie"]);
+        assert_eq!(pattern.exact_count(&'e'), Some(2));
+        assert!(Word::try_from("elder").unwrap().matches(&pattern));
+        assert!(!Word::try_from("eerie").unwrap().matches(&pattern));
     }
 }
@@ -0,0 +1,239 @@
+use rayon::prelude::*;
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{ErrorKind, Write},
+    path::PathBuf,
+};
+
+use crate::{
+    game::{encode_outcome, Game, GameState, OutcomeCode, TileOutcome},
+    strategy::{progress_bar, StrategyVerbosity},
+    words::{HasWords, WordPtr, WordlistPtr},
+};
+
+/// The name shown in the header of shared results.
+const SHARE_TITLE: &str = "Crustacean Wordle";
+
+/// The emoji palette used for shared results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShareTheme {
+    /// Green and yellow squares, like the official site.
+    Standard,
+    /// Orange and blue squares, for colour-blind players.
+    HighContrast,
+}
+
+/// Build the familiar emoji grid of a game's outcomes, with a header like
+/// "Crustacean Wordle #N 4/6" (or "X/6" if the guesser was defeated), where the puzzle
+/// number is only included for daily puzzles.
+pub fn share_grid(game: &Game, theme: ShareTheme) -> String {
+    let score = match game.current_state() {
+        GameState::GuesserVictory => game.num_guesses().to_string(),
+        _ => "X".to_string(),
+    };
+    let title = match game.puzzle_number() {
+        Some(puzzle_number) => format!("{} #{}", SHARE_TITLE, puzzle_number),
+        None => SHARE_TITLE.to_string(),
+    };
+
+    let mut grid = format!("{} {}/{}\n", title, score, game.max_guesses());
+    for guess in game.guesses() {
+        grid.push('\n');
+        grid.extend(guess.outcome.iter().map(|outcome| emoji(outcome, theme)));
+    }
+
+    grid
+}
+
+/// Write the emoji grid of a game to the file at `path`.
+pub fn write_share_grid(
+    path: &PathBuf,
+    game: &Game,
+    theme: ShareTheme,
+) -> Result<(), std::io::Error> {
+    let mut file = File::create(path)?;
+    writeln!(file, "{}", share_grid(game, theme))
+}
+
+/// The emoji representing `outcome` in shared results.
+fn emoji(outcome: &TileOutcome, theme: ShareTheme) -> char {
+    match (outcome, theme) {
+        (TileOutcome::Gray, _) => '⬛',
+        (TileOutcome::Green, ShareTheme::Standard) => '🟩',
+        (TileOutcome::Yellow, ShareTheme::Standard) => '🟨',
+        (TileOutcome::Green, ShareTheme::HighContrast) => '🟧',
+        (TileOutcome::Yellow, ShareTheme::HighContrast) => '🟦',
+    }
+}
+
+/// Map a single emoji of a shared grid (in either palette) to its outcome.
+fn outcome_of_emoji(ch: char) -> Option<TileOutcome> {
+    match ch {
+        '🟩' | '🟧' => Some(TileOutcome::Green),
+        '🟨' | '🟦' => Some(TileOutcome::Yellow),
+        '⬛' | '⬜' => Some(TileOutcome::Gray),
+        _ => None,
+    }
+}
+
+/// Parse the rows of a pasted emoji grid (in either palette) into outcomes. Lines without any
+/// tiles, such as the header, are ignored.
+pub fn parse_share_grid(text: &str) -> Result<Vec<Vec<TileOutcome>>, std::io::Error> {
+    let invalid = |msg: &str| std::io::Error::new(ErrorKind::InvalidData, msg.to_string());
+
+    let mut rows: Vec<Vec<TileOutcome>> = vec![];
+    for line in text.lines() {
+        // Skip whitespace and emoji variation selectors.
+        let chars: Vec<char> = line
+            .chars()
+            .filter(|ch| !ch.is_whitespace() && *ch != '\u{fe0f}')
+            .collect();
+        if !chars.iter().any(|ch| outcome_of_emoji(*ch).is_some()) {
+            continue;
+        }
+
+        let row = chars
+            .into_iter()
+            .map(outcome_of_emoji)
+            .collect::<Option<Vec<TileOutcome>>>()
+            .ok_or_else(|| invalid(&format!("Could not parse grid row '{}'", line)))?;
+        rows.push(row);
+    }
+
+    if rows.is_empty() {
+        return Err(invalid("Grid does not contain any rows"));
+    }
+
+    if rows.iter().any(|row| row.len() != rows[0].len()) {
+        return Err(invalid("Grid rows do not all have the same length"));
+    }
+
+    let is_win = |row: &Vec<TileOutcome>| row.iter().all(|item| item == &TileOutcome::Green);
+    if rows[..rows.len() - 1].iter().any(is_win) {
+        return Err(invalid("Grid contains a winning row before its last row"));
+    }
+
+    Ok(rows)
+}
+
+/// A potential answer consistent with a shared grid, along with the guesses that could have
+/// produced each row of the grid.
+pub struct GridSolution {
+    pub answer: WordPtr,
+    pub row_guesses: Vec<Vec<WordPtr>>,
+}
+
+impl GridSolution {
+    /// The number of guess sequences that could have produced the grid for this answer.
+    pub fn num_sequences(&self) -> f64 {
+        self.row_guesses
+            .iter()
+            .map(|guesses| guesses.len() as f64)
+            .product()
+    }
+}
+
+/// Compute which answers in `answerlist` (and which guesses in `guesslist` for each row) are
+/// consistent with the given grid `rows`. Solutions are in answer list order.
+pub fn solve_share_grid(
+    rows: &[Vec<TileOutcome>],
+    guesslist: &WordlistPtr,
+    answerlist: &WordlistPtr,
+    verbosity: StrategyVerbosity,
+) -> Vec<GridSolution> {
+    let answers = answerlist.possible_words();
+    let guesses = guesslist.possible_words();
+
+    // Identical rows are consistent with identical guesses, so only match each distinct row once.
+    let mut row_indices: HashMap<OutcomeCode, usize> = HashMap::new();
+    for row in rows {
+        let num_distinct = row_indices.len();
+        row_indices
+            .entry(encode_outcome(row))
+            .or_insert(num_distinct);
+    }
+    let row_length = rows.first().map_or(0, |row| row.len());
+
+    let pb = progress_bar(&verbosity, answers.len());
+
+    let solutions: Vec<GridSolution> = answers
+        .par_iter()
+        .filter_map(|answer| {
+            if answer.len() != row_length {
+                return None;
+            }
+
+            let mut distinct_guesses: Vec<Vec<WordPtr>> = vec![vec![]; row_indices.len()];
+            for guess in guesses {
+                let outcome = answer.outcome_code(guess);
+                if let Some(idx) = row_indices.get(&outcome) {
+                    distinct_guesses[*idx].push(guess.clone());
+                }
+            }
+
+            pb.inc(1);
+
+            if distinct_guesses.iter().any(|guesses| guesses.is_empty()) {
+                return None;
+            }
+
+            Some(GridSolution {
+                answer: answer.clone(),
+                row_guesses: rows
+                    .iter()
+                    .map(|row| distinct_guesses[row_indices[&encode_outcome(row)]].clone())
+                    .collect(),
+            })
+        })
+        .collect();
+
+    pb.finish_and_clear();
+
+    solutions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_share_grid() {
+        let rows =
+            parse_share_grid("Crustacean Wordle 3/6\n\n⬛🟨⬛⬛🟩\n🟩⬛🟦⬛🟧\n🟩🟩🟩🟩🟩\n")
+                .unwrap();
+        assert_eq!(
+            rows,
+            vec![
+                vec![
+                    TileOutcome::Gray,
+                    TileOutcome::Yellow,
+                    TileOutcome::Gray,
+                    TileOutcome::Gray,
+                    TileOutcome::Green,
+                ],
+                vec![
+                    TileOutcome::Green,
+                    TileOutcome::Gray,
+                    TileOutcome::Yellow,
+                    TileOutcome::Gray,
+                    TileOutcome::Green,
+                ],
+                vec![TileOutcome::Green; 5],
+            ]
+        );
+
+        for theme in [ShareTheme::Standard, ShareTheme::HighContrast] {
+            let row: String = rows[1]
+                .iter()
+                .map(|outcome| emoji(outcome, theme))
+                .collect();
+            assert_eq!(parse_share_grid(&row).unwrap(), vec![rows[1].clone()]);
+        }
+
+        assert!(parse_share_grid("Crustacean Wordle 1/6").is_err());
+        assert!(parse_share_grid("⬛🟨⬛⬛🟩\n⬛🟨⬛🟩").is_err());
+        assert!(parse_share_grid("⬛🟨x⬛🟩").is_err());
+        assert!(parse_share_grid("🟩🟩🟩🟩🟩\n⬛🟨⬛⬛🟩").is_err());
+    }
+}
@@ -0,0 +1,273 @@
+use console::{style, Term};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    ffi::OsString,
+    fs::{self, File},
+    io::{BufReader, BufWriter, ErrorKind, Write},
+    path::{Path, PathBuf},
+};
+
+/// The width (in characters) of the widest bar in the guess distribution.
+const DISTRIBUTION_BAR_WIDTH: u32 = 30;
+
+/// Persistent statistics over all games played by the user, like the official site's.
+#[derive(Default, Serialize, Deserialize)]
+pub struct Stats {
+    pub games_played: u32,
+    pub games_won: u32,
+    /// Games in which the strategy was consulted for a guess, and how many of them were won.
+    pub assisted_games_played: u32,
+    pub assisted_games_won: u32,
+    pub current_streak: u32,
+    pub max_streak: u32,
+    /// The number of games won in `idx + 1` guesses.
+    pub guess_distribution: Vec<u32>,
+    /// The first result of each daily puzzle played, by puzzle number.
+    #[serde(default)]
+    pub daily_results: BTreeMap<usize, DailyResult>,
+}
+
+/// The result of a daily puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DailyResult {
+    /// The number of guesses taken, or None if the puzzle was lost.
+    pub num_guesses: Option<usize>,
+    pub assisted: bool,
+}
+
+impl Stats {
+    /// The default stats file location in the user's data directory, if it can be determined.
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::data_dir().map(|dir| dir.join("crustacean-wordle").join("stats.json"))
+    }
+
+    /// Read stats from the JSON file at `path`, or return empty stats if it does not exist.
+    pub fn read(path: &PathBuf) -> Result<Self, std::io::Error> {
+        match File::open(path) {
+            Ok(file) => serde_json::from_reader(BufReader::new(file)).map_err(std::io::Error::from),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(Stats::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Write these stats as JSON to the file at `path`, creating its directory if needed. The
+    /// stats are written to a temporary file first, so that `path` is replaced all at once.
+    pub fn write(&self, path: &PathBuf) -> Result<(), std::io::Error> {
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)?;
+        }
+
+        let temp_path = with_suffix(path, ".tmp");
+        let mut writer = BufWriter::new(File::create(&temp_path)?);
+        serde_json::to_writer_pretty(&mut writer, self).map_err(std::io::Error::from)?;
+        writer.flush()?;
+        writer.get_ref().sync_all()?;
+        drop(writer);
+
+        fs::rename(&temp_path, path)
+    }
+
+    /// Record a finished game, won in `num_guesses` guesses (or lost if None), noting whether
+    /// the strategy was consulted and which daily puzzle it was (if any).
+    pub fn record(
+        &mut self,
+        num_guesses: Option<usize>,
+        assisted: bool,
+        puzzle_number: Option<usize>,
+    ) {
+        if let Some(puzzle_number) = puzzle_number {
+            self.daily_results
+                .entry(puzzle_number)
+                .or_insert(DailyResult {
+                    num_guesses,
+                    assisted,
+                });
+        }
+
+        self.games_played += 1;
+        if assisted {
+            self.assisted_games_played += 1;
+        }
+
+        match num_guesses {
+            Some(num_guesses) => {
+                self.games_won += 1;
+                if assisted {
+                    self.assisted_games_won += 1;
+                }
+
+                self.current_streak += 1;
+                self.max_streak = self.max_streak.max(self.current_streak);
+
+                if self.guess_distribution.len() < num_guesses {
+                    self.guess_distribution.resize(num_guesses, 0);
+                }
+                self.guess_distribution[num_guesses - 1] += 1;
+            }
+            None => self.current_streak = 0,
+        }
+    }
+
+    /// The percentage of games won, rounded down.
+    pub fn win_percentage(&self) -> u32 {
+        if self.games_played == 0 {
+            return 0;
+        }
+
+        self.games_won * 100 / self.games_played
+    }
+
+    /// Pretty-print the stats, highlighting the distribution row for a win in `highlight` guesses.
+    pub fn pretty_print(
+        &self,
+        highlight: Option<usize>,
+        max_guesses: usize,
+    ) -> Result<(), std::io::Error> {
+        let term = Term::stdout();
+
+        term.write_line(&style("STATISTICS").bold().to_string())?;
+        term.write_line(
+            format!(
+                "Played: {}  Win %: {}  Current Streak: {}  Max Streak: {}",
+                style(self.games_played).bold(),
+                style(self.win_percentage()).bold(),
+                style(self.current_streak).bold(),
+                style(self.max_streak).bold()
+            )
+            .as_str(),
+        )?;
+        term.write_line(
+            format!(
+                "Assisted: {} played, {} won",
+                style(self.assisted_games_played).bold(),
+                style(self.assisted_games_won).bold()
+            )
+            .as_str(),
+        )?;
+        term.write_line("")?;
+
+        term.write_line(&style("GUESS DISTRIBUTION").bold().to_string())?;
+        let max_count = self.guess_distribution.iter().max().cloned().unwrap_or(0);
+        for idx in 0..max_guesses.max(self.guess_distribution.len()) {
+            let count = self.guess_distribution.get(idx).cloned().unwrap_or(0);
+            let width = (count * DISTRIBUTION_BAR_WIDTH)
+                .checked_div(max_count)
+                .unwrap_or(0) as usize;
+
+            let bar = format!("{} {}", " ".repeat(width), count);
+            let bar = if highlight == Some(idx + 1) {
+                style(bar).black().on_green()
+            } else {
+                style(bar).white().on_color256(242)
+            };
+            term.write_line(format!("{} {}", idx + 1, bar).as_str())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Update the stats file at `path` with a finished game and pretty-print the result.
+pub fn record_game(
+    path: &PathBuf,
+    num_guesses: Option<usize>,
+    assisted: bool,
+    puzzle_number: Option<usize>,
+    max_guesses: usize,
+) -> Result<(), std::io::Error> {
+    let mut stats = match Stats::read(path) {
+        Ok(stats) => stats,
+        Err(err) => {
+            let backup_path = with_suffix(path, ".bak");
+            fs::rename(path, &backup_path)?;
+            Term::stdout().write_line(
+                format!(
+                    "Could not read stats ({}), so they were moved to {:?} and started afresh.",
+                    err, backup_path
+                )
+                .as_str(),
+            )?;
+            Stats::default()
+        }
+    };
+    stats.record(num_guesses, assisted, puzzle_number);
+    stats.write(path)?;
+    stats.pretty_print(num_guesses, max_guesses)
+}
+
+/// The path of the file next to `path` whose name has `suffix` appended.
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = OsString::from(path.as_os_str());
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record() {
+        let mut stats = Stats::default();
+        stats.record(Some(4), false, None);
+        stats.record(Some(3), true, Some(10));
+        stats.record(Some(4), false, None);
+
+        assert_eq!(stats.games_played, 3);
+        assert_eq!(stats.games_won, 3);
+        assert_eq!(stats.assisted_games_played, 1);
+        assert_eq!(stats.assisted_games_won, 1);
+        assert_eq!(stats.current_streak, 3);
+        assert_eq!(stats.max_streak, 3);
+        assert_eq!(stats.guess_distribution, vec![0, 0, 1, 2]);
+
+        stats.record(None, false, Some(11));
+        stats.record(Some(6), false, Some(10));
+
+        assert_eq!(stats.games_played, 5);
+        assert_eq!(stats.win_percentage(), 80);
+        assert_eq!(stats.current_streak, 1);
+        assert_eq!(stats.max_streak, 3);
+        assert_eq!(stats.guess_distribution, vec![0, 0, 1, 2, 0, 1]);
+        assert_eq!(
+            stats.daily_results,
+            BTreeMap::from([
+                (
+                    10,
+                    DailyResult {
+                        num_guesses: Some(3),
+                        assisted: true
+                    }
+                ),
+                (
+                    11,
+                    DailyResult {
+                        num_guesses: None,
+                        assisted: false
+                    }
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_record_game_with_corrupt_stats() {
+        let dir =
+            std::env::temp_dir().join(format!("crustacean-wordle-stats-{}", std::process::id()));
+        let path = dir.join("stats.json");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&path, "not json").unwrap();
+
+        record_game(&path, Some(3), false, None, 6).unwrap();
+        let backup = fs::read_to_string(with_suffix(&path, ".bak")).unwrap();
+        let stats = Stats::read(&path).unwrap();
+        let temp_exists = with_suffix(&path, ".tmp").exists();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(backup, "not json");
+        assert_eq!(stats.games_played, 1);
+        assert_eq!(stats.guess_distribution, vec![0, 0, 1]);
+        assert!(!temp_exists);
+    }
+}
@@ -1,35 +1,77 @@
+use console::style;
 use indicatif::{ProgressBar, ProgressStyle};
 use rayon::prelude::*;
 use std::{
-    collections::{BTreeMap, HashMap},
+    collections::{BTreeMap, HashSet},
     fmt::Display,
     sync::Arc,
 };
 
 use crate::{
-    game::{Guess, TileOutcome},
+    filter::FilterBackend,
+    game::{decode_outcome, winning_outcome_code, Guess, OutcomeCode, TileOutcome},
     pattern::Pattern,
-    words::{CanPatternFilter, HasWords, WordPtr, WordlistPtr},
+    words::{CanPatternFilter, HasWordScores, HasWords, WordPtr, WordlistPtr},
 };
 
 /// The entropy value used in Entropy-based strategies to indicate a win when there is only one option.
 const ENTROPY_STRATEGY_WIN_VALUE: f64 = -1000.0_f64;
 
+/// The typical information gained by a guess, used by the Bayesian strategy to convert expected
+/// entropy into an expected number of guesses. The best opening guesses gain about 5.9 bits over
+/// the original answer list, and later guesses somewhat less, as fewer candidates remain to be
+/// told apart.
+const BAYESIAN_STRATEGY_BITS_PER_GUESS: f64 = 5.0_f64;
+
+/// The maximum number of outcome buckets shown when explaining a guess.
+const EXPLANATION_MAX_BUCKETS: usize = 15;
+
+/// The maximum number of sample words shown per outcome bucket when explaining a guess.
+const EXPLANATION_MAX_SAMPLES: usize = 5;
+
+/// The maximum number of Newton iterations taken when fitting a `SigmoidPrior`.
+const SIGMOID_PRIOR_FIT_ITERATIONS: usize = 100;
+
 /// Represents verbosity options for a strategy.
-#[derive(PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum StrategyVerbosity {
     Silent,
     PrettyPrint,
     Debug,
 }
 
+/// A thread-safe function initializing a strategy from the guess list and answer list.
+pub type StrategyInit = dyn Fn(WordlistPtr, WordlistPtr) -> Box<dyn Strategy> + Send + Sync;
+
 /// Represents a game strategy for use with `Game`.
 pub trait Strategy: Display {
-    /// All the guesses this strategy will consider making.
+    /// All the words this strategy still considers potential answers.
     fn extant_guesses(&self) -> &[WordPtr];
 
     /// The current best guess according to this strategy.
-    fn chosen_guess(&self) -> Option<WordPtr>;
+    fn chosen_guess(&self) -> Option<WordPtr> {
+        self.ranked_guesses()
+            .into_iter()
+            .next()
+            .map(|(_, guess)| guess)
+    }
+
+    /// All the guesses this strategy will consider making, paired with their
+    /// strategy-specific scores and ordered from best to worst.
+    fn ranked_guesses(&self) -> Vec<(f64, WordPtr)>;
+
+    /// The name of this strategy, as used on the command line and in saved games.
+    fn name(&self) -> &'static str;
+
+    /// The temperature of this strategy's frequency prior, if it has one.
+    fn temperature(&self) -> Option<f64> {
+        None
+    }
+
+    /// The name of this strategy's filter backend as used on the command line, if it has one.
+    fn filter_backend(&self) -> Option<&'static str> {
+        None
+    }
 
     /// A callback function for the game to register a new `Guess`
     /// with this strategy.
@@ -39,14 +81,228 @@ pub trait Strategy: Display {
     fn metrics(&self) -> BTreeMap<String, f64>;
 
     /// Pretty-print strategy information.
-    fn pretty_print(&self, history: &Vec<BTreeMap<String, f64>>);
+    fn pretty_print(&self, history: &Vec<BTreeMap<String, f64>>) {
+        println!("{}", self);
+
+        for (idx, metrics) in history.iter().enumerate() {
+            println!("History Entry #{}: {:?}", idx + 1, metrics);
+        }
+    }
 
     /// Set strategy verbosity.
     fn set_verbosity(&mut self, verbosity: StrategyVerbosity);
 }
 
+/// The outcome buckets that `guess` partitions `words` into, each with the words it contains.
+fn outcome_buckets(guess: &WordPtr, words: &[WordPtr]) -> BTreeMap<Vec<TileOutcome>, Vec<WordPtr>> {
+    let mut buckets: BTreeMap<Vec<TileOutcome>, Vec<WordPtr>> = BTreeMap::new();
+    for actual_word in words {
+        let outcome = actual_word.outcome_of_guess(guess.clone());
+        buckets
+            .entry(outcome)
+            .or_default()
+            .push(actual_word.clone());
+    }
+    buckets
+}
+
+/// The outcome buckets that `guess` partitions `words` into, each with its number of words.
+fn outcome_counts(guess: &WordPtr, words: &[WordPtr]) -> BTreeMap<OutcomeCode, usize> {
+    let mut counts: BTreeMap<OutcomeCode, usize> = BTreeMap::new();
+    for actual_word in words {
+        let outcome = actual_word.outcome_code(guess);
+        *counts.entry(outcome).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// A set of extant words that all produce the same outcome for some guess.
+pub struct OutcomeBucket {
+    pub outcome: Vec<TileOutcome>,
+    pub words: Vec<WordPtr>,
+}
+
+/// A breakdown of a guess against the extant words, see `Game::explain_guess`.
+pub struct GuessExplanation {
+    pub guess: WordPtr,
+    /// The outcome buckets, ordered from largest to smallest.
+    pub buckets: Vec<OutcomeBucket>,
+    /// The expected information gained in bits, assuming each extant word is equally likely.
+    pub expected_information: f64,
+    /// The 1-indexed rank of the guess under the strategy, if it would consider it at all.
+    pub rank: Option<usize>,
+    /// The strategy-specific score of the guess, if it is ranked.
+    pub score: Option<f64>,
+    /// The number of guesses ranked by the strategy.
+    pub num_ranked: usize,
+}
+
+impl GuessExplanation {
+    /// Explain `guess` against the given `extant_words`, ranking it among the strategy's
+    /// `ranked` guesses (as returned by `Strategy::ranked_guesses`).
+    pub fn new(guess: &WordPtr, extant_words: &[WordPtr], ranked: &[(f64, WordPtr)]) -> Self {
+        let mut buckets: Vec<OutcomeBucket> = outcome_buckets(guess, extant_words)
+            .into_iter()
+            .map(|(outcome, words)| OutcomeBucket { outcome, words })
+            .collect();
+        buckets.sort_by_key(|bucket| std::cmp::Reverse(bucket.words.len()));
+
+        let num_extant = extant_words.len() as f64;
+        let expected_information = buckets
+            .iter()
+            .map(|bucket| {
+                let probability = bucket.words.len() as f64 / num_extant;
+                probability * (1.0_f64 / probability).log2()
+            })
+            .sum();
+
+        let position = ranked
+            .iter()
+            .position(|(_, ranked_guess)| Arc::ptr_eq(ranked_guess, guess));
+
+        GuessExplanation {
+            guess: guess.clone(),
+            buckets,
+            expected_information,
+            rank: position.map(|idx| idx + 1),
+            score: position.map(|idx| ranked[idx].0),
+            num_ranked: ranked.len(),
+        }
+    }
+
+    /// The largest outcome bucket, i.e. the worst case for this guess.
+    pub fn worst_case(&self) -> Option<&OutcomeBucket> {
+        self.buckets.first()
+    }
+}
+
+impl Display for GuessExplanation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{} would split {} extant words into {} outcomes.",
+            style(self.guess.get_word().to_ascii_uppercase()).bold(),
+            self.buckets
+                .iter()
+                .map(|bucket| bucket.words.len())
+                .sum::<usize>(),
+            self.buckets.len()
+        )?;
+        writeln!(
+            f,
+            "Expected information: {:.3} bits",
+            self.expected_information
+        )?;
+        if let Some(worst_case) = self.worst_case() {
+            writeln!(f, "Worst case: {} words remaining", worst_case.words.len())?;
+        }
+        match (self.rank, self.score) {
+            (Some(rank), Some(score)) => writeln!(
+                f,
+                "Strategy rank: #{} of {} (score: {:.3})",
+                rank, self.num_ranked, score
+            )?,
+            _ => writeln!(f, "Strategy rank: not considered")?,
+        }
+        writeln!(f)?;
+
+        for bucket in self.buckets.iter().take(EXPLANATION_MAX_BUCKETS) {
+            let guess = Guess {
+                guess: self.guess.letters().collect(),
+                outcome: bucket.outcome.clone(),
+            };
+            let samples: Vec<String> = bucket
+                .words
+                .iter()
+                .take(EXPLANATION_MAX_SAMPLES)
+                .map(|word| word.get_word())
+                .collect();
+            let ellipsis = if bucket.words.len() > EXPLANATION_MAX_SAMPLES {
+                ", ..."
+            } else {
+                ""
+            };
+            writeln!(
+                f,
+                "{} {:>5}  {}{}",
+                guess,
+                bucket.words.len(),
+                samples.join(", "),
+                ellipsis
+            )?;
+        }
+
+        if self.buckets.len() > EXPLANATION_MAX_BUCKETS {
+            writeln!(
+                f,
+                "... and {} more outcomes",
+                self.buckets.len() - EXPLANATION_MAX_BUCKETS
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Create a progress bar for evaluating `len` guesses, hidden if `verbosity` is silent.
+pub(crate) fn progress_bar(verbosity: &StrategyVerbosity, len: usize) -> ProgressBar {
+    let pb = match verbosity {
+        StrategyVerbosity::PrettyPrint | StrategyVerbosity::Debug => ProgressBar::new(len as u64),
+        _ => ProgressBar::hidden(),
+    };
+
+    let sty = ProgressStyle::with_template(
+        "[{spinner:.green} {elapsed_precise}] {wide_bar:.cyan/blue} {pos:>7}/{len:7} {msg} (eta {eta})",
+    )
+    .unwrap()
+    .progress_chars("##-");
+    pb.set_style(sty);
+
+    pb
+}
+
+/// Whether a strategy prefers guesses with higher or lower scores.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ScoreOrder {
+    HigherIsBetter,
+    LowerIsBetter,
+}
+
+/// Score each of `guesses` in parallel with `score`, given each guess and its index, showing
+/// progress unless `verbosity` is silent, and return them ordered from best to worst.
+fn rank_guesses(
+    guesses: &[WordPtr],
+    verbosity: &StrategyVerbosity,
+    order: ScoreOrder,
+    score: impl Fn(usize, &WordPtr) -> f64 + Sync,
+) -> Vec<(f64, WordPtr)> {
+    let pb = progress_bar(verbosity, guesses.len());
+
+    let mut guess_score_pairs: Vec<(f64, WordPtr)> = guesses
+        .par_iter()
+        .enumerate()
+        .map(|(guess_idx, guess)| {
+            let guess_score = score(guess_idx, guess);
+            pb.inc(1);
+            (guess_score, guess.clone())
+        })
+        .collect();
+
+    match order {
+        ScoreOrder::HigherIsBetter => {
+            guess_score_pairs.sort_by(|(s1, _), (s2, _)| s2.total_cmp(s1))
+        }
+        ScoreOrder::LowerIsBetter => guess_score_pairs.sort_by(|(s1, _), (s2, _)| s1.total_cmp(s2)),
+    }
+
+    pb.finish_and_clear();
+
+    guess_score_pairs
+}
+
 pub struct EntropyStrategy {
     knowledge: Pattern,
+    backend: FilterBackend,
     verbosity: StrategyVerbosity,
     guesslist: WordlistPtr,
     extant: Arc<dyn CanPatternFilter + Send + Sync>,
@@ -60,13 +316,8 @@ impl Display for EntropyStrategy {
             self.extant_guesses().len(),
             self.extant.unweighted_entropy()
         )?;
-        writeln!(f, "Disallowed characters: {:?}", self.knowledge.disallowed)?;
-        writeln!(
-            f,
-            "Must-contain characters: {:?}",
-            self.knowledge.must_contain
-        )?;
-        writeln!(f, "Constraints: {:?}", self.knowledge.constraints)?;
+        writeln!(f, "Filter backend: {}", self.backend.name())?;
+        write!(f, "{}", self.knowledge)?;
         writeln!(f)?;
 
         Ok(())
@@ -74,85 +325,298 @@ impl Display for EntropyStrategy {
 }
 
 impl Strategy for EntropyStrategy {
+    fn name(&self) -> &'static str {
+        "entropy"
+    }
+
+    fn filter_backend(&self) -> Option<&'static str> {
+        match self.backend {
+            FilterBackend::Pattern => Some("pattern"),
+            FilterBackend::Outcomes(_) => Some("outcomes"),
+        }
+    }
+
     fn extant_guesses(&self) -> &[WordPtr] {
         self.extant.possible_words()
     }
 
     fn register_guess(&mut self, guess: &Guess) {
         self.knowledge = self.knowledge.ingest(guess);
-        self.extant = self.extant.filter_pattern(&self.knowledge);
+        self.extant = self.backend.filter(
+            self.extant.as_ref(),
+            &self.guesslist,
+            &self.knowledge,
+            guess,
+        );
     }
 
-    fn chosen_guess(&self) -> Option<WordPtr> {
+    fn ranked_guesses(&self) -> Vec<(f64, WordPtr)> {
         let all_guesses = self.guesslist.possible_words();
         let extant_words = self.extant.possible_words();
-
-        let pb = match self.verbosity {
-            StrategyVerbosity::PrettyPrint | StrategyVerbosity::Debug => {
-                ProgressBar::new(all_guesses.len() as u64)
-            }
-            _ => ProgressBar::hidden(),
-        };
-
-        let sty = ProgressStyle::with_template(
-            "[{spinner:.green} {elapsed_precise}] {wide_bar:.cyan/blue} {pos:>7}/{len:7} {msg} (eta {eta})",
-        )
-        .unwrap()
-        .progress_chars("##-");
-        pb.set_style(sty);
+        let extant_indices = self.extant.word_indices();
 
         let current_entropy = self.extant.unweighted_entropy();
-        let mut guess_score_pairs: Vec<(f64, WordPtr)> = all_guesses
-            .par_iter()
-            .map(|guess| {
-                let mut possible_patterns: HashMap<Vec<TileOutcome>, usize> = HashMap::new();
-                for actual_word in extant_words {
-                    let outcome = actual_word.outcome_of_guess(guess.clone());
-                    *possible_patterns.entry(outcome).or_insert(0) += 1;
-                }
+        rank_guesses(
+            all_guesses,
+            &self.verbosity,
+            ScoreOrder::HigherIsBetter,
+            |guess_idx, guess| {
+                let possible_patterns: Vec<(OutcomeCode, usize)> = match &self.backend {
+                    FilterBackend::Outcomes(Some(table)) => {
+                        table.outcome_counts(guess_idx, &extant_indices)
+                    }
+                    _ => outcome_counts(guess, extant_words).into_iter().collect(),
+                };
 
                 let mut total_gain = 0.0_f64;
                 for (outcome, count) in possible_patterns {
-                    let guess = Guess {
-                        guess: guess.get_word().chars().collect(),
-                        outcome: outcome.clone(),
-                    };
-                    let pattern = self.knowledge.ingest(&guess);
-                    let sublist = self.extant.filter_pattern(&pattern);
-
                     let new_entropy = if current_entropy == 0.0_f64
-                        && outcome.iter().all(|item| item == &TileOutcome::Green)
+                        && outcome == winning_outcome_code(guess.len())
                     {
                         ENTROPY_STRATEGY_WIN_VALUE
+                    } else if let FilterBackend::Pattern = self.backend {
+                        let guess_outcome = Guess {
+                            guess: guess.letters().collect(),
+                            outcome: decode_outcome(outcome, guess.len()),
+                        };
+                        let pattern = self.knowledge.ingest(&guess_outcome);
+                        (self.extant.count_pattern(&pattern) as f64).log2()
                     } else {
-                        sublist.unweighted_entropy()
+                        // Outcome buckets are exactly the candidates that would remain.
+                        (count as f64).log2()
                     };
 
                     let improvement = current_entropy - new_entropy;
                     total_gain += (count as f64) * improvement;
                 }
 
-                pb.inc(1);
-
                 // Since words.len() is constant, maximizing `total_gain` is equivalent to
                 // maximizing average gain.
-                (total_gain, guess.clone())
+                total_gain
+            },
+        )
+    }
+
+    fn metrics(&self) -> BTreeMap<String, f64> {
+        BTreeMap::from([
+            (
+                "extant_guesses".to_string(),
+                self.extant_guesses().len() as f64,
+            ),
+            (
+                "unweighted_entropy".to_string(),
+                self.extant.unweighted_entropy(),
+            ),
+            (
+                "weighted_entropy".to_string(),
+                self.extant.weighted_entropy(),
+            ),
+        ])
+    }
+
+    fn set_verbosity(&mut self, verbosity: StrategyVerbosity) {
+        self.verbosity = verbosity;
+    }
+}
+
+impl EntropyStrategy {
+    /// Initializes a new Strategy that guesses from `guesslist`, and computes extant
+    /// candidates and entropy over `answerlist` (whose scores act as the prior over answers).
+    pub fn init(guesslist: WordlistPtr, answerlist: WordlistPtr) -> Box<dyn Strategy> {
+        EntropyStrategy::with_backend(guesslist, answerlist, FilterBackend::Pattern)
+    }
+
+    /// Initializes a new Strategy as in `init`, narrowing down candidates with `backend`. Any
+    /// outcome table it uses must have been built from `guesslist` and `answerlist`.
+    pub fn with_backend(
+        guesslist: WordlistPtr,
+        answerlist: WordlistPtr,
+        backend: FilterBackend,
+    ) -> Box<dyn Strategy> {
+        Box::new(EntropyStrategy {
+            knowledge: Pattern::default(),
+            backend,
+            verbosity: StrategyVerbosity::Silent,
+            guesslist,
+            extant: answerlist,
+        })
+    }
+}
+
+/// Maps normalized wordlist scores to prior probabilities of a word being the answer using a
+/// sigmoid centered at `center`, whose steepness is controlled by `temperature` (lower
+/// temperatures sharpen the divide between common and uncommon words).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SigmoidPrior {
+    pub center: f64,
+    pub temperature: f64,
+}
+
+impl Default for SigmoidPrior {
+    fn default() -> Self {
+        SigmoidPrior {
+            center: 0.0_f64,
+            temperature: 1.0_f64,
+        }
+    }
+}
+
+impl SigmoidPrior {
+    /// The (unnormalized) prior weight of a word with the given normalized `score`.
+    pub fn weight(&self, score: f64) -> f64 {
+        1.0_f64 / (1.0_f64 + (-(score - self.center) / self.temperature).exp())
+    }
+
+    /// Fit the sigmoid against the answer list, i.e. find the center and temperature that
+    /// maximize the likelihood of exactly the words in `answerlist` being the answers among
+    /// the words in `guesslist` (a one-dimensional logistic regression on the scores).
+    pub fn fit(guesslist: &WordlistPtr, answerlist: &WordlistPtr) -> Self {
+        let answers: HashSet<String> = answerlist
+            .possible_words()
+            .iter()
+            .map(|word| word.get_word())
+            .collect();
+        let samples: Vec<(f64, f64)> = guesslist
+            .possible_words()
+            .iter()
+            .zip(guesslist.possible_scores())
+            .map(|(word, score)| {
+                let label = if answers.contains(&word.get_word()) {
+                    1.0_f64
+                } else {
+                    0.0_f64
+                };
+                (*score, label)
             })
             .collect();
 
-        guess_score_pairs.sort_by(|(s1, _), (s2, _)| s2.partial_cmp(s1).unwrap());
-        let best_guess = guess_score_pairs.first();
+        // Fit p = sigmoid(slope * score + intercept) by Newton's method.
+        let (mut slope, mut intercept) = (1.0_f64, 0.0_f64);
+        for _ in 0..SIGMOID_PRIOR_FIT_ITERATIONS {
+            let (mut g_slope, mut g_intercept) = (0.0_f64, 0.0_f64);
+            let (mut h_ss, mut h_si, mut h_ii) = (0.0_f64, 0.0_f64, 0.0_f64);
+            for (score, label) in &samples {
+                let p = 1.0_f64 / (1.0_f64 + (-(slope * score + intercept)).exp());
+                let w = p * (1.0_f64 - p);
+                g_slope += (label - p) * score;
+                g_intercept += label - p;
+                h_ss += w * score * score;
+                h_si += w * score;
+                h_ii += w;
+            }
+
+            let det = h_ss * h_ii - h_si * h_si;
+            if det.abs() < f64::EPSILON {
+                break;
+            }
 
-        pb.finish_and_clear();
+            let step_slope = (h_ii * g_slope - h_si * g_intercept) / det;
+            let step_intercept = (h_ss * g_intercept - h_si * g_slope) / det;
+            slope += step_slope;
+            intercept += step_intercept;
 
-        if self.verbosity == StrategyVerbosity::Debug {
-            for idx in 0..guess_score_pairs.len().min(5) {
-                let (score, guess) = guess_score_pairs.get(idx)?;
-                println!("{} ({})", guess, score);
+            if step_slope.abs() < 1e-9 && step_intercept.abs() < 1e-9 {
+                break;
             }
         }
 
-        best_guess.map(|(_, guess)| guess).cloned()
+        if !slope.is_finite() || slope <= 0.0_f64 {
+            return SigmoidPrior::default();
+        }
+
+        SigmoidPrior {
+            center: -intercept / slope,
+            temperature: 1.0_f64 / slope,
+        }
+    }
+}
+
+/// A strategy that treats every word in the guess list as a potential answer, weighted by a
+/// frequency prior, and chooses the guess minimizing the expected number of guesses to win.
+pub struct BayesianStrategy {
+    knowledge: Pattern,
+    verbosity: StrategyVerbosity,
+    guesslist: WordlistPtr,
+    extant: Arc<dyn CanPatternFilter + Send + Sync>,
+    prior: SigmoidPrior,
+}
+
+impl Display for BayesianStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "# Extant Guesses: {} (prior entropy: {})",
+            self.extant_guesses().len(),
+            self.prior_entropy()
+        )?;
+        writeln!(f, "Prior: {:?}", self.prior)?;
+        write!(f, "{}", self.knowledge)?;
+        writeln!(f)?;
+
+        Ok(())
+    }
+}
+
+impl Strategy for BayesianStrategy {
+    fn name(&self) -> &'static str {
+        "bayesian"
+    }
+
+    fn temperature(&self) -> Option<f64> {
+        Some(self.prior.temperature)
+    }
+
+    fn extant_guesses(&self) -> &[WordPtr] {
+        self.extant.possible_words()
+    }
+
+    fn register_guess(&mut self, guess: &Guess) {
+        self.knowledge = self.knowledge.ingest(guess);
+        self.extant = self.extant.filter_pattern(&self.knowledge);
+    }
+
+    fn ranked_guesses(&self) -> Vec<(f64, WordPtr)> {
+        let all_guesses = self.guesslist.possible_words();
+        let extant_words = self.extant.possible_words();
+        let weights = self.prior_weights();
+        let total_weight: f64 = weights.iter().sum();
+
+        let current_entropy = self.prior_entropy();
+        rank_guesses(
+            all_guesses,
+            &self.verbosity,
+            ScoreOrder::LowerIsBetter,
+            |_, guess| {
+                let mut possible_patterns: BTreeMap<OutcomeCode, f64> = BTreeMap::new();
+                for (actual_word, weight) in extant_words.iter().zip(&weights) {
+                    let outcome = actual_word.outcome_code(guess);
+                    *possible_patterns.entry(outcome).or_insert(0.0_f64) += weight;
+                }
+
+                let mut expected_information = 0.0_f64;
+                let mut win_probability = 0.0_f64;
+                for (outcome, weight) in possible_patterns {
+                    let probability = weight / total_weight;
+                    expected_information -= probability * probability.log2();
+
+                    if outcome == winning_outcome_code(guess.len()) {
+                        win_probability = probability;
+                    }
+                }
+
+                // The winning outcome leaves no uncertainty, so the expected entropy remaining
+                // after a loss is the total expected entropy remaining, conditioned on not winning.
+                let remaining_entropy = if win_probability < 1.0_f64 {
+                    ((current_entropy - expected_information) / (1.0_f64 - win_probability))
+                        .max(0.0_f64)
+                } else {
+                    0.0_f64
+                };
+                win_probability
+                    + (1.0_f64 - win_probability)
+                        * (2.0_f64 + remaining_entropy / BAYESIAN_STRATEGY_BITS_PER_GUESS)
+            },
+        )
     }
 
     fn metrics(&self) -> BTreeMap<String, f64> {
@@ -169,30 +633,134 @@ impl Strategy for EntropyStrategy {
                 "weighted_entropy".to_string(),
                 self.extant.weighted_entropy(),
             ),
+            ("prior_entropy".to_string(), self.prior_entropy()),
         ])
     }
 
-    fn pretty_print(&self, history: &Vec<BTreeMap<String, f64>>) {
-        println!("{}", self);
-
-        for (idx, metrics) in history.iter().enumerate() {
-            println!("History Entry #{}: {:?}", idx + 1, metrics);
-        }
-    }
-
     fn set_verbosity(&mut self, verbosity: StrategyVerbosity) {
         self.verbosity = verbosity;
     }
 }
 
-impl EntropyStrategy {
-    /// Initializes a new Strategy with the given Game.
-    pub fn init(wordlist: WordlistPtr) -> Box<dyn Strategy> {
-        Box::new(EntropyStrategy {
+impl BayesianStrategy {
+    /// Initializes a new Strategy with a prior fit against `answerlist`.
+    pub fn init(guesslist: WordlistPtr, answerlist: WordlistPtr) -> Box<dyn Strategy> {
+        let prior = SigmoidPrior::fit(&guesslist, &answerlist);
+        BayesianStrategy::with_prior(guesslist, prior)
+    }
+
+    /// Initializes a new Strategy with the given `prior`. All words in `guesslist` are
+    /// considered potential answers.
+    pub fn with_prior(guesslist: WordlistPtr, prior: SigmoidPrior) -> Box<dyn Strategy> {
+        Box::new(BayesianStrategy {
             knowledge: Pattern::default(),
             verbosity: StrategyVerbosity::Silent,
-            guesslist: wordlist.clone(),
-            extant: wordlist,
+            guesslist: guesslist.clone(),
+            extant: guesslist,
+            prior,
         })
     }
+
+    /// The prior weight of each extant word.
+    fn prior_weights(&self) -> Vec<f64> {
+        self.extant
+            .possible_scores()
+            .iter()
+            .map(|score| self.prior.weight(*score))
+            .collect()
+    }
+
+    /// The entropy of the prior distribution over the extant words.
+    fn prior_entropy(&self) -> f64 {
+        let weights = self.prior_weights();
+        let total_weight: f64 = weights.iter().sum();
+        weights
+            .iter()
+            .map(|weight| weight / total_weight)
+            .filter(|probability| *probability > 0.0_f64)
+            .map(|probability| -probability * probability.log2())
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::words::{Word, Wordlist};
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+
+    /// The number of synthetic words the prior is fit against.
+    const NUM_SYNTHETIC_WORDS: usize = 20000;
+
+    /// A distinct five-letter word for each `idx`, spelling it in base 26.
+    fn synthetic_word(mut idx: usize) -> WordPtr {
+        let letters: String = (0..5)
+            .map(|_| {
+                let letter = (b'a' + (idx % 26) as u8) as char;
+                idx /= 26;
+                letter
+            })
+            .collect();
+        Arc::new(Word::try_from(letters).unwrap())
+    }
+
+    #[test]
+    fn test_fit_sigmoid_prior() {
+        let truth = SigmoidPrior {
+            center: 0.5_f64,
+            temperature: 0.4_f64,
+        };
+
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        let (mut words, mut scores) = (vec![], vec![]);
+        let (mut answers, mut answer_scores) = (vec![], vec![]);
+        for idx in 0..NUM_SYNTHETIC_WORDS {
+            let word = synthetic_word(idx);
+            let score = rng.gen_range(-3.0_f64..3.0_f64);
+            if rng.gen_bool(truth.weight(score)) {
+                answers.push(word.clone());
+                answer_scores.push(score);
+            }
+            words.push(word);
+            scores.push(score);
+        }
+
+        let guesslist = Arc::new(Wordlist::from_words(words, scores));
+        let answerlist = Arc::new(Wordlist::from_words(answers, answer_scores));
+        let fitted = SigmoidPrior::fit(&guesslist, &answerlist);
+
+        assert!(
+            (fitted.center - truth.center).abs() < 0.05_f64,
+            "{:?}",
+            fitted
+        );
+        assert!(
+            (fitted.temperature - truth.temperature).abs() < 0.05_f64,
+            "{:?}",
+            fitted
+        );
+    }
+
+    #[test]
+    fn test_bayesian_picks_last_candidate() {
+        let words: Vec<WordPtr> = ["crane", "crate", "grate", "plate", "slate"]
+            .into_iter()
+            .map(|word| Arc::new(Word::try_from(word).unwrap()))
+            .collect();
+        let guesslist = Arc::new(Wordlist::from_words(words, vec![0.0_f64; 5]));
+        let mut strategy = BayesianStrategy::with_prior(guesslist, SigmoidPrior::default());
+
+        let answer = Word::try_from("crate").unwrap();
+        let guess = Arc::new(Word::try_from("grate").unwrap());
+        strategy.register_guess(&Guess {
+            guess: guess.letters().collect(),
+            outcome: answer.outcome_of_guess(guess),
+        });
+
+        assert_eq!(strategy.extant_guesses().len(), 1);
+        let chosen = strategy.chosen_guess().unwrap();
+        assert_eq!(chosen.as_str(), "crate");
+        assert_eq!(strategy.ranked_guesses()[0], (1.0_f64, chosen));
+    }
 }
@@ -0,0 +1,395 @@
+use ratatui::{
+    crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    DefaultTerminal, Frame,
+};
+use std::time::{Duration, Instant};
+
+use crustacean_wordle::{
+    game::{GameState, TileOutcome},
+    strategy::StrategyVerbosity,
+    words::WORD_LENGTH,
+    Game,
+};
+
+use crate::keyboard::{self, KeyboardLayout};
+
+/// The delay between revealing consecutive tiles of a guess.
+const TILE_REVEAL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// How long to wait for input before redrawing while a reveal is animating.
+const ANIMATION_FRAME: Duration = Duration::from_millis(50);
+
+/// The number of nearest spelling suggestions offered for words not in the word list.
+const NUM_SPELLING_SUGGESTIONS: usize = 3;
+
+/// The key bindings shown at the bottom of the screen.
+const KEY_BINDINGS: &str = "Enter: guess  Tab: hint  Ctrl-U: undo  Ctrl-N: new game  Esc: quit";
+
+/// The state of the full-screen TUI on top of a `Game`.
+struct App<'a> {
+    game: &'a mut Game,
+    keyboard_layout: KeyboardLayout,
+    input: String,
+    message: Option<String>,
+    hint: Option<String>,
+    /// The row currently being revealed and when its reveal started.
+    reveal: Option<(usize, Instant)>,
+    quit: bool,
+}
+
+/// Map a tile outcome to its colour in the TUI.
+fn tile_color(outcome: TileOutcome) -> Color {
+    match outcome {
+        TileOutcome::Gray => Color::Indexed(242),
+        TileOutcome::Yellow => Color::Yellow,
+        TileOutcome::Green => Color::Green,
+    }
+}
+
+/// A single tile of the grid, optionally coloured by its outcome.
+fn tile(ch: char, outcome: Option<TileOutcome>) -> Span<'static> {
+    let style = match outcome {
+        Some(outcome) => Style::default().fg(Color::White).bg(tile_color(outcome)),
+        None => Style::default().fg(Color::White).bg(Color::Indexed(236)),
+    };
+    Span::styled(
+        format!(" {} ", ch.to_ascii_uppercase()),
+        style.add_modifier(Modifier::BOLD),
+    )
+}
+
+impl<'a> App<'a> {
+    fn new(game: &'a mut Game, keyboard_layout: KeyboardLayout) -> Self {
+        App {
+            game,
+            keyboard_layout,
+            input: String::new(),
+            message: None,
+            hint: None,
+            reveal: None,
+            quit: false,
+        }
+    }
+
+    /// The number of tiles revealed so far in the row being revealed, if any.
+    fn revealed_tiles(&self) -> Option<(usize, usize)> {
+        self.reveal.map(|(row, started)| {
+            let revealed = (started.elapsed().as_millis() / TILE_REVEAL_INTERVAL.as_millis()) + 1;
+            (row, revealed as usize)
+        })
+    }
+
+    /// Whether a reveal is still in progress.
+    fn is_animating(&self) -> bool {
+        matches!(self.revealed_tiles(), Some((_, revealed)) if revealed <= WORD_LENGTH)
+    }
+
+    /// Draw the whole screen.
+    fn draw(&self, frame: &mut Frame) {
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1),
+                Constraint::Min(0),
+                Constraint::Length(5),
+                Constraint::Length(1),
+            ])
+            .split(frame.area());
+
+        let title = Paragraph::new(Line::from(Span::styled(
+            "CRUSTACEAN WORDLE",
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+        )))
+        .alignment(Alignment::Center);
+        frame.render_widget(title, rows[0]);
+
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(rows[1]);
+        frame.render_widget(self.grid(), columns[0]);
+        frame.render_widget(self.hint_panel(), columns[1]);
+        frame.render_widget(self.keyboard(), rows[2]);
+
+        let help = Paragraph::new(Span::styled(
+            KEY_BINDINGS,
+            Style::default().fg(Color::DarkGray),
+        ))
+        .alignment(Alignment::Center);
+        frame.render_widget(help, rows[3]);
+    }
+
+    /// The tile grid, with past guesses, the typing row and empty rows.
+    fn grid(&self) -> Paragraph<'static> {
+        let revealing = self.revealed_tiles();
+        let mut lines: Vec<Line> = vec![];
+
+        for (row, guess) in self.game.guesses().enumerate() {
+            let revealed = match revealing {
+                Some((reveal_row, revealed)) if reveal_row == row => revealed,
+                _ => WORD_LENGTH,
+            };
+            let tiles: Vec<Span> = guess
+                .paired_iter()
+                .enumerate()
+                .flat_map(|(idx, (ch, outcome))| {
+                    let outcome = if idx < revealed { Some(*outcome) } else { None };
+                    [tile(*ch, outcome), Span::raw(" ")]
+                })
+                .collect();
+            lines.push(Line::from(tiles));
+            lines.push(Line::default());
+        }
+
+        for row in self.game.num_guesses()..self.game.max_guesses() {
+            let typed: Vec<char> = if row == self.game.num_guesses() && !self.game.is_over() {
+                self.input.chars().collect()
+            } else {
+                vec![]
+            };
+            let tiles: Vec<Span> = (0..WORD_LENGTH)
+                .flat_map(|idx| [tile(*typed.get(idx).unwrap_or(&' '), None), Span::raw(" ")])
+                .collect();
+            lines.push(Line::from(tiles));
+            lines.push(Line::default());
+        }
+
+        Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::ALL).title("Board"))
+    }
+
+    /// The panel showing the candidate count, strategy hints and messages.
+    fn hint_panel(&self) -> Paragraph<'static> {
+        let mut lines = vec![Line::from(vec![
+            Span::raw("Candidates remaining: "),
+            Span::styled(
+                self.game.extant_guesses().len().to_string(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+        ])];
+
+        if let Some(hint) = &self.hint {
+            lines.push(Line::default());
+            lines.push(Line::from(Span::styled(
+                hint.clone(),
+                Style::default().fg(Color::Cyan),
+            )));
+        }
+
+        if let Some(message) = &self.message {
+            lines.push(Line::default());
+            lines.push(Line::from(Span::styled(
+                message.clone(),
+                Style::default().add_modifier(Modifier::BOLD),
+            )));
+        }
+
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: true })
+            .block(Block::default().borders(Borders::ALL).title("Hints"))
+    }
+
+    /// The on-screen keyboard, coloured by letter state (ignoring unrevealed tiles).
+    fn keyboard(&self) -> Paragraph<'static> {
+        let num_revealed = if self.is_animating() {
+            self.game.num_guesses() - 1
+        } else {
+            self.game.num_guesses()
+        };
+        let states = keyboard::letter_states(self.game.guesses().take(num_revealed));
+
+        let lines: Vec<Line> = self
+            .keyboard_layout
+            .rows()
+            .iter()
+            .map(|row| {
+                let keys: Vec<Span> = row
+                    .chars()
+                    .flat_map(|ch| {
+                        let key = match states[(ch as u8 - b'a') as usize] {
+                            Some(outcome) => tile(ch, Some(outcome)),
+                            None => Span::styled(
+                                format!(" {} ", ch.to_ascii_uppercase()),
+                                Style::default().fg(Color::Black).bg(Color::Indexed(250)),
+                            ),
+                        };
+                        [key, Span::raw(" ")]
+                    })
+                    .collect();
+                Line::from(keys)
+            })
+            .collect();
+
+        Paragraph::new(lines).alignment(Alignment::Center)
+    }
+
+    /// Handle a key press.
+    fn handle_key(&mut self, terminal: &mut DefaultTerminal, key: KeyEvent) -> std::io::Result<()> {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+
+        match key.code {
+            KeyCode::Esc => self.quit = true,
+            KeyCode::Char('c') if ctrl => self.quit = true,
+            KeyCode::Char('u') if ctrl => self.undo(),
+            KeyCode::Char('n') if ctrl => self.new_game(),
+            KeyCode::Tab => self.show_hint(terminal)?,
+            KeyCode::Enter => self.submit(),
+            KeyCode::Backspace => {
+                self.input.pop();
+            }
+            KeyCode::Char(ch)
+                if ch.is_ascii_alphabetic()
+                    && !ctrl
+                    && self.input.len() < WORD_LENGTH
+                    && !self.game.is_over() =>
+            {
+                self.input.push(ch.to_ascii_lowercase());
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Submit the typed row as a guess, if it is a valid word.
+    fn submit(&mut self) {
+        if self.game.is_over() {
+            return;
+        }
+
+        if self.input.len() < WORD_LENGTH {
+            self.message = Some("Not enough letters.".to_string());
+            return;
+        }
+
+        let word = match self.game.get_wordlist().get_word(&self.input) {
+            Some(word) => word,
+            None => {
+                let suggestions: Vec<String> = self
+                    .game
+                    .get_wordlist()
+                    .suggestions(&self.input, NUM_SPELLING_SUGGESTIONS)
+                    .iter()
+                    .map(|word| word.get_word().to_ascii_uppercase())
+                    .collect();
+                self.message = Some(format!(
+                    "{} is not in the word list. Did you mean: {}?",
+                    self.input.to_ascii_uppercase(),
+                    suggestions.join(", ")
+                ));
+                return;
+            }
+        };
+
+        if let Some(violation) = self.game.hard_mode_violation(&word) {
+            self.message = Some(violation);
+            return;
+        }
+
+        self.game.make_guess(word);
+        self.reveal = Some((self.game.num_guesses() - 1, Instant::now()));
+        self.input.clear();
+        self.hint = None;
+        self.message = match self.game.current_state() {
+            GameState::GuesserVictory => Some(format!(
+                "Solved in {}/{}! Ctrl-N for a new game.",
+                self.game.num_guesses(),
+                self.game.max_guesses()
+            )),
+            GameState::GuesserDefeat => Some(format!(
+                "The word was {}. Ctrl-N for a new game.",
+                self.game
+                    .revealed_word()
+                    .map(|word| word.get_word().to_ascii_uppercase())
+                    .unwrap_or_default()
+            )),
+            _ => None,
+        };
+    }
+
+    /// Ask the strategy for its suggested guess.
+    fn show_hint(&mut self, terminal: &mut DefaultTerminal) -> std::io::Result<()> {
+        if self.game.is_over() {
+            return Ok(());
+        }
+
+        self.hint = Some("Thinking...".to_string());
+        terminal.draw(|frame| self.draw(frame))?;
+
+        self.game.set_assisted();
+        self.hint = Some(match self.game.next_guess() {
+            Some(guess) => format!(
+                "Strategy suggests {}.",
+                guess.get_word().to_ascii_uppercase()
+            ),
+            None => "The strategy has no suggestion.".to_string(),
+        });
+
+        Ok(())
+    }
+
+    /// Undo the last guess.
+    fn undo(&mut self) {
+        if self.game.num_guesses() > 0 {
+            self.game.rewind(self.game.num_guesses() - 1);
+        }
+
+        self.reveal = None;
+        self.hint = None;
+        self.message = None;
+    }
+
+    /// Start a new game with a random word.
+    fn new_game(&mut self) {
+        self.game.reset();
+        self.game.choose_seeded_random_word(rand::random());
+
+        self.input.clear();
+        self.reveal = None;
+        self.hint = None;
+        self.message = None;
+    }
+}
+
+/// Run a full-screen, keyboard-driven TUI for playing `game` until the user quits.
+pub fn run_tui(game: &mut Game, keyboard_layout: KeyboardLayout) -> std::io::Result<()> {
+    // Progress bars would draw over the TUI.
+    game.set_verbosity(StrategyVerbosity::Silent);
+
+    let mut terminal = ratatui::init();
+    let mut app = App::new(game, keyboard_layout);
+    let result = run_app(&mut terminal, &mut app);
+    ratatui::restore();
+
+    result
+}
+
+fn run_app(terminal: &mut DefaultTerminal, app: &mut App) -> std::io::Result<()> {
+    while !app.quit {
+        terminal.draw(|frame| app.draw(frame))?;
+
+        let timeout = if app.is_animating() {
+            ANIMATION_FRAME
+        } else {
+            TILE_REVEAL_INTERVAL
+        };
+        if !event::poll(timeout)? {
+            continue;
+        }
+
+        if let Event::Key(key) = event::read()? {
+            if key.kind == KeyEventKind::Press {
+                app.handle_key(terminal, key)?;
+            }
+        }
+    }
+
+    Ok(())
+}
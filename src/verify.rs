@@ -0,0 +1,180 @@
+use console::style;
+use std::{collections::HashSet, fmt::Display, sync::Arc};
+
+use crate::{
+    game::{encode_outcome, Guess, OutcomeCode},
+    pattern::Pattern,
+    words::{CanPatternFilter, HasWords, Word, WordPtr, WordlistPtr},
+};
+
+/// The result of checking that filtering candidates by the `Pattern` learned from a sequence
+/// of guesses agrees with replaying the guesses against each candidate, see `verify_guesses`.
+pub struct Verification {
+    /// The number of candidates that reproduce the outcome of every guess.
+    pub num_consistent: usize,
+    /// Candidates that reproduce the outcome of every guess, but were filtered out.
+    pub wrongly_excluded: Vec<WordPtr>,
+    /// Candidates that were kept by filtering, but do not reproduce the outcome of every guess.
+    pub wrongly_included: Vec<WordPtr>,
+}
+
+impl Verification {
+    /// Whether or not pattern filtering and outcome replay agree.
+    pub fn is_consistent(&self) -> bool {
+        self.wrongly_excluded.is_empty() && self.wrongly_included.is_empty()
+    }
+}
+
+impl Display for Verification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_consistent() {
+            return write!(
+                f,
+                "Verified: pattern filtering agrees with outcome replay ({} candidates).",
+                self.num_consistent
+            );
+        }
+
+        let words = |words: &[WordPtr]| -> String {
+            words
+                .iter()
+                .map(|word| word.as_str())
+                .collect::<Vec<&str>>()
+                .join(", ")
+        };
+
+        writeln!(
+            f,
+            "{}",
+            style("Verification failed: pattern filtering disagrees with outcome replay!")
+                .red()
+                .bold()
+        )?;
+        writeln!(f, "Wrongly excluded: {}", words(&self.wrongly_excluded))?;
+        write!(f, "Wrongly included: {}", words(&self.wrongly_included))
+    }
+}
+
+/// Check that repeatedly applying `Pattern::ingest` and `filter_pattern` for each of `guesses`
+/// leaves exactly the words in `candidates` that reproduce the outcome of every guess.
+pub fn verify_guesses(guesses: &[Guess], candidates: &WordlistPtr) -> Verification {
+    let mut pattern = Pattern::default();
+    let mut filtered: Arc<dyn CanPatternFilter + Send + Sync> = candidates.clone();
+    for guess in guesses {
+        pattern = pattern.ingest(guess);
+        filtered = filtered.filter_pattern(&pattern);
+    }
+
+    let replayed = replay_guesses(guesses, candidates);
+
+    let filtered_set: HashSet<&str> = filtered
+        .possible_words()
+        .iter()
+        .map(|word| word.as_str())
+        .collect();
+    let replayed_set: HashSet<&str> = replayed.iter().map(|word| word.as_str()).collect();
+
+    Verification {
+        num_consistent: replayed.len(),
+        wrongly_excluded: replayed
+            .iter()
+            .filter(|word| !filtered_set.contains(word.as_str()))
+            .cloned()
+            .collect(),
+        wrongly_included: filtered
+            .possible_words()
+            .iter()
+            .filter(|word| !replayed_set.contains(word.as_str()))
+            .cloned()
+            .collect(),
+    }
+}
+
+/// Return the words in `candidates` that reproduce the outcome of every one of `guesses`.
+pub fn replay_guesses(guesses: &[Guess], candidates: &WordlistPtr) -> Vec<WordPtr> {
+    // A guess whose letters do not make up a word is reproduced by no candidate.
+    let replays: Vec<(Option<Word>, OutcomeCode)> = guesses
+        .iter()
+        .map(|guess| (guess.word(), encode_outcome(&guess.outcome)))
+        .collect();
+    candidates
+        .possible_words()
+        .iter()
+        .filter(|word| {
+            replays.iter().all(|(guess, outcome)| {
+                guess.as_ref().is_some_and(|guess| {
+                    guess.len() == word.len() && word.outcome_code(guess) == *outcome
+                })
+            })
+        })
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::words::Wordlist;
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+    use std::path::PathBuf;
+
+    /// The number of random games to verify, for each way of choosing guesses.
+    const NUM_RANDOM_GAMES: usize = 200;
+
+    /// Letters for random non-word guesses, chosen to produce many duplicate letters.
+    const DUPLICATE_HEAVY_LETTERS: &[u8] = b"aeelrsst";
+
+    /// Check that every prefix of the guesses of a random game against a random answer from
+    /// `answerlist` verifies, with each guess chosen by `choose_guess`.
+    fn verify_random_games(
+        answerlist: &WordlistPtr,
+        mut choose_guess: impl FnMut(&mut ChaCha8Rng) -> WordPtr,
+    ) {
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        for _ in 0..NUM_RANDOM_GAMES {
+            let answer = answerlist.random_word_with(&mut rng).unwrap();
+            let mut guesses: Vec<Guess> = vec![];
+
+            for _ in 0..rng.gen_range(1..=6) {
+                let guess = choose_guess(&mut rng);
+                guesses.push(Guess {
+                    guess: guess.letters().collect(),
+                    outcome: answer.outcome_of_guess(guess.clone()),
+                });
+
+                let verification = verify_guesses(&guesses, answerlist);
+                let words: Vec<String> = guesses
+                    .iter()
+                    .map(|guess| guess.guess.iter().collect())
+                    .collect();
+                assert!(
+                    verification.is_consistent(),
+                    "Answer {}, guesses {:?}: {}",
+                    answer,
+                    words,
+                    verification
+                );
+                assert!(verification.num_consistent >= 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_verify_random_games() {
+        let data_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("data");
+        let guesslist = Wordlist::init(&data_dir.join("guesslist.txt")).unwrap();
+        let answerlist = Wordlist::init(&data_dir.join("answerlist.txt")).unwrap();
+
+        verify_random_games(&answerlist, |rng| guesslist.random_word_with(rng).unwrap());
+
+        verify_random_games(&answerlist, |rng| {
+            let letters: String = (0..answerlist.possible_words()[0].len())
+                .map(|_| {
+                    DUPLICATE_HEAVY_LETTERS[rng.gen_range(0..DUPLICATE_HEAVY_LETTERS.len())] as char
+                })
+                .collect();
+            Arc::new(Word::try_from(letters).unwrap())
+        });
+    }
+}
@@ -1,16 +1,17 @@
+use std::collections::HashMap;
 use std::fmt::{Debug, Display};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::fs;
+use std::io::{self, ErrorKind};
 use std::path::PathBuf;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
-use counter::Counter;
 use rand::seq::SliceRandom;
-use rand::thread_rng;
+use rand::{thread_rng, RngCore};
 
 use crate::bitmask::*;
-use crate::game::TileOutcome;
-use crate::pattern::{Pattern, PlaceConstraint};
+use crate::bitset::{WordBitset, BLOCK_BITS};
+use crate::game::{OutcomeCode, TileOutcome, OUTCOME_CODE_BASE};
+use crate::pattern::Pattern;
 
 /// Allowed word length (all words not of this length are filtered out in the Wordlist initializer).
 pub const WORD_LENGTH: usize = 5;
@@ -19,16 +20,21 @@ pub const WORD_LENGTH: usize = 5;
 // const FREQ_SCORE_THRESHOLD: f64 = 1000_f64;
 const FREQ_SCORE_THRESHOLD: f64 = 0_f64;
 
-#[derive(Default)]
+/// The maximum number of letters a `Word` can hold.
+pub const MAX_WORD_LENGTH: usize = 16;
+
+/// A word stored inline as lowercase ASCII letters, alongside its per-letter counts, so that
+/// matching and scoring never touch the heap.
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
 pub struct Word {
-    word: Vec<char>,
-    letters_mask: LetterBitmask,
+    letters: [u8; MAX_WORD_LENGTH],
+    len: u8,
+    counts: [u8; ALPHABET_SIZE],
 }
 
 impl Debug for Word {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let s: String = String::from_iter(self.word.iter());
-        write!(f, "{}", s)
+        write!(f, "{}", self.as_str())
     }
 }
 
@@ -36,113 +42,161 @@ pub type WordPtr = Arc<Word>;
 
 impl Display for Word {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.get_word())
+        write!(f, "{}", self.as_str())
     }
 }
 
+/// The index of letter `ch` in the alphabet.
+#[inline(always)]
+fn letter_index(ch: u8) -> usize {
+    (ch - b'a') as usize
+}
+
 impl Word {
-    /// Whether or not the word contains character `ch`.
+    /// The number of letters in the word.
     #[inline(always)]
-    fn has_letter(&self, ch: &char) -> bool {
-        self.letters_mask & LetterBitmask::char_bitmask(ch) != 0
+    pub fn len(&self) -> usize {
+        self.len as usize
     }
 
-    /// Number of occurrences of character `ch`.
+    /// Whether or not the word has no letters.
     #[inline(always)]
-    fn num_occurrences(&self, ch: &char) -> usize {
-        self.word.iter().filter(|c| c == &ch).count()
-    }
-
-    /// Whether or not the word obeys the `PlaceConstraint` for the
-    /// given `usize` index.
-    fn obeys_constraint(&self, cons: (&usize, &PlaceConstraint)) -> bool {
-        let (idx, cons) = cons;
-        let value = self
-            .word
-            .get(*idx)
-            .expect("Constraint index out of bounds!");
-
-        match cons {
-            PlaceConstraint::IsChar(ch) => ch == value,
-            PlaceConstraint::IsNotChars(chars) => chars & LetterBitmask::char_bitmask(value) == 0,
-        }
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The word's letters as lowercase ASCII bytes.
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.letters[..self.len()]
+    }
+
+    /// The word as a string slice.
+    pub fn as_str(&self) -> &str {
+        std::str::from_utf8(self.as_bytes()).expect("Words are always ASCII!")
+    }
+
+    /// An iterator over the word's letters.
+    pub fn letters(&self) -> impl Iterator<Item = char> + '_ {
+        self.as_bytes().iter().map(|&ch| ch as char)
     }
 
     /// Whether or not this word matches the `Pattern` given in `pattern`.
     pub fn matches(&self, pattern: &Pattern) -> bool {
-        if self.letters_mask & pattern.disallowed != 0 {
-            return false;
-        }
+        let positions_allowed = self
+            .as_bytes()
+            .iter()
+            .zip(&pattern.allowed)
+            .all(|(&ch, allowed)| allowed & (1 << letter_index(ch)) != 0);
 
-        for (ch, count) in &pattern.must_contain {
-            if self.num_occurrences(ch) < *count {
-                return false;
+        positions_allowed
+            && self
+                .counts
+                .iter()
+                .zip(pattern.min_counts.iter().zip(&pattern.max_counts))
+                .all(|(count, (min, max))| min <= count && count <= max)
+    }
+
+    /// Return the outcome of the given guess against this word.
+    pub fn outcome_of_guess(&self, guess: WordPtr) -> Vec<TileOutcome> {
+        debug_assert_eq!(guess.len(), self.len());
+
+        let word = self.as_bytes();
+        let guess = guess.as_bytes();
+        let mut outcomes: Vec<TileOutcome> = vec![TileOutcome::Gray; guess.len()];
+        let mut counts = self.counts;
+
+        for idx in 0..guess.len() {
+            if word[idx] == guess[idx] {
+                outcomes[idx] = TileOutcome::Green;
+                counts[letter_index(word[idx])] -= 1;
             }
         }
 
-        for cons in &pattern.constraints {
-            if !self.obeys_constraint(cons) {
-                return false;
+        for (idx, &ch) in guess.iter().enumerate() {
+            let count = &mut counts[letter_index(ch)];
+            if outcomes[idx] == TileOutcome::Gray && *count > 0 {
+                *count -= 1;
+                outcomes[idx] = TileOutcome::Yellow;
             }
         }
 
-        true
+        outcomes
     }
 
-    /// Return the outcome of the given guess against this word.
-    pub fn outcome_of_guess(&self, guess: WordPtr) -> Vec<TileOutcome> {
-        debug_assert_eq!(guess.get_word().len(), self.get_word().len());
-
-        let word_length_range = 0..guess.get_word().len();
-        let mut outcomes: Vec<TileOutcome> = word_length_range
-            .clone()
-            .map(|_| TileOutcome::Gray)
-            .collect();
+    /// Return the outcome of the given guess against this word, packed into an
+    /// `OutcomeCode`. Equivalent to `outcome_of_guess`, but allocation-free.
+    pub fn outcome_code(&self, guess: &Word) -> OutcomeCode {
+        debug_assert_eq!(guess.len(), self.len());
 
-        let mut counts = self.word.iter().collect::<Counter<_, i32>>();
+        let word = self.as_bytes();
+        let guess = guess.as_bytes();
+        let mut counts = self.counts;
+        let mut greens = [false; MAX_WORD_LENGTH];
 
-        for idx in word_length_range {
-            if self.word[idx] == guess.word[idx] {
-                outcomes[idx] = TileOutcome::Green;
-                counts[&&(self.word[idx])] -= 1;
+        for idx in 0..guess.len() {
+            if word[idx] == guess[idx] {
+                greens[idx] = true;
+                counts[letter_index(word[idx])] -= 1;
             }
         }
 
-        for (idx, ch) in guess.word.iter().enumerate() {
-            if outcomes[idx] == TileOutcome::Gray && self.has_letter(ch) && counts[&ch] > 0 {
-                counts[&ch] -= 1;
-                outcomes[idx] = TileOutcome::Yellow;
+        let mut code: OutcomeCode = 0;
+        let mut place: OutcomeCode = 1;
+        for (idx, &ch) in guess.iter().enumerate() {
+            let count = &mut counts[letter_index(ch)];
+            if greens[idx] {
+                code += 2 * place;
+            } else if *count > 0 {
+                *count -= 1;
+                code += place;
             }
+            place *= OUTCOME_CODE_BASE;
         }
 
-        outcomes
+        code
     }
 
-    /// Getter for the word as a string.
+    /// Getter for the word as an owned string (prefer `as_str` in hot loops).
     pub fn get_word(&self) -> String {
-        self.word.iter().collect()
+        self.as_str().to_string()
     }
 }
 
-impl From<String> for Word {
-    fn from(str: String) -> Self {
-        let word: Vec<char> = str.chars().collect();
-        let mask = LetterBitmask::compute_bitmask(word.iter());
-        Word {
-            word,
-            letters_mask: mask,
+impl TryFrom<&str> for Word {
+    type Error = io::Error;
+
+    /// Build a `Word` from `str`, failing unless it consists of at most `MAX_WORD_LENGTH`
+    /// ASCII letters.
+    fn try_from(str: &str) -> Result<Self, Self::Error> {
+        if str.len() > MAX_WORD_LENGTH || !str.bytes().all(|ch| ch.is_ascii_alphabetic()) {
+            return Err(io::Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "'{}' is not a word of at most {} ASCII letters",
+                    str, MAX_WORD_LENGTH
+                ),
+            ));
+        }
+
+        let mut word = Word {
+            len: str.len() as u8,
+            ..Default::default()
+        };
+        for (idx, ch) in str.bytes().map(|ch| ch.to_ascii_lowercase()).enumerate() {
+            word.letters[idx] = ch;
+            word.counts[letter_index(ch)] += 1;
         }
+
+        Ok(word)
     }
 }
 
-impl From<&str> for Word {
-    fn from(str: &str) -> Self {
-        let word: Vec<char> = str.chars().collect();
-        let mask = LetterBitmask::compute_bitmask(word.iter());
-        Word {
-            word,
-            letters_mask: mask,
-        }
+impl TryFrom<String> for Word {
+    type Error = io::Error;
+
+    fn try_from(str: String) -> Result<Self, Self::Error> {
+        Word::try_from(str.as_str())
     }
 }
 
@@ -151,15 +205,24 @@ pub trait HasWords {
 
     /// Return a random word if non-empty or None.
     fn random_word(&self) -> Option<WordPtr> {
-        let mut rng = thread_rng();
+        self.random_word_with(&mut thread_rng())
+    }
+
+    /// Return a random word chosen using `rng` if non-empty or None.
+    fn random_word_with(&self, rng: &mut dyn RngCore) -> Option<WordPtr> {
         let words = self.possible_words();
-        words.choose(&mut rng).cloned()
+        words.choose(rng).cloned()
     }
 
     /// Returns the unweighted entropy of this distribution (i.e. the -log2 of the cardinality of
     /// the remaining guessing space).
     fn unweighted_entropy(&self) -> f64 {
-        (self.possible_words().len() as f64).log2()
+        (self.num_words() as f64).log2()
+    }
+
+    /// Returns the number of possible words.
+    fn num_words(&self) -> usize {
+        self.possible_words().len()
     }
 }
 
@@ -183,42 +246,30 @@ pub trait HasWordScores: HasWords {
 }
 
 pub trait CanPatternFilter: HasWordScores {
+    /// Returns a `SubWordlist` of the words for which `keep` holds, given each word and its
+    /// index in the underlying `Wordlist`.
+    fn filter_indexed(&self, keep: &dyn Fn(usize, &Word) -> bool) -> Arc<SubWordlist>;
+
+    /// Returns the number of words matching the given `Pattern`, without allocating.
+    fn count_pattern(&self, pattern: &Pattern) -> usize;
+
+    /// Returns the index of each word in the underlying `Wordlist`.
+    fn word_indices(&self) -> Vec<usize>;
+
     /// Returns a `SubWordlist` of the words matching the given `Pattern`.
     fn filter_pattern(&self, pattern: &Pattern) -> Arc<SubWordlist> {
-        let words = self.possible_words();
-        let scores = self.possible_scores();
-        let indices: Vec<usize> = words
-            .iter()
-            .enumerate()
-            .filter_map(|(idx, word)| {
-                if word.matches(pattern) {
-                    Some(idx)
-                } else {
-                    None
-                }
-            })
-            .collect();
-
-        Arc::new(SubWordlist {
-            words: indices
-                .iter()
-                .filter_map(|idx| words.get(*idx))
-                .cloned()
-                .collect(),
-            scores: indices
-                .iter()
-                .filter_map(|idx| scores.get(*idx))
-                .cloned()
-                .collect(),
-        })
+        self.filter_indexed(&|_, word| word.matches(pattern))
     }
 }
 
 /// The Wordlist object contains the list of all valid words and associated frequencies.
 #[derive(Default)]
 pub struct Wordlist {
-    words: Vec<WordPtr>,
-    scores: Vec<f64>,
+    words: Arc<Vec<WordPtr>>,
+    scores: Arc<Vec<f64>>,
+    /// The index in `words` of each word, for constant-time lookups.
+    index: HashMap<String, usize>,
+    pattern_index: Arc<PatternIndex>,
 }
 
 pub type WordlistPtr = Arc<Wordlist>;
@@ -235,7 +286,48 @@ impl HasWordScores for Wordlist {
     }
 }
 
-impl CanPatternFilter for Wordlist {}
+impl CanPatternFilter for Wordlist {
+    fn filter_indexed(&self, keep: &dyn Fn(usize, &Word) -> bool) -> Arc<SubWordlist> {
+        let mut members = WordBitset::empty(self.words.len());
+        for (idx, word) in self.words.iter().enumerate() {
+            if keep(idx, word) {
+                members.insert(idx);
+            }
+        }
+
+        Arc::new(SubWordlist::new(self, members))
+    }
+
+    fn word_indices(&self) -> Vec<usize> {
+        (0..self.words.len()).collect()
+    }
+
+    fn count_pattern(&self, pattern: &Pattern) -> usize {
+        self.pattern_index.count(None, pattern)
+    }
+
+    fn filter_pattern(&self, pattern: &Pattern) -> Arc<SubWordlist> {
+        let members = self.pattern_index.matching(None, pattern);
+        Arc::new(SubWordlist::new(self, members))
+    }
+}
+
+/// Parse line `line_number` of a wordlist file into a `(word, score)` entry, or None if the
+/// line is blank. A score that is not a number is an error naming the line.
+fn parse_entry(line_number: usize, line: &str) -> Result<Option<(&str, f64)>, io::Error> {
+    let mut columns = line.split_whitespace();
+    let Some(word) = columns.next() else {
+        return Ok(None);
+    };
+    let score = columns.last().unwrap_or("0");
+    match score.parse() {
+        Ok(score) => Ok(Some((word, score))),
+        Err(_) => Err(io::Error::new(
+            ErrorKind::InvalidData,
+            format!("Invalid score '{}' on line {}", score, line_number),
+        )),
+    }
+}
 
 impl Wordlist {
     /// Initialize a `Wordlist` from the wordlist at the file path `path`. The file
@@ -243,47 +335,102 @@ impl Wordlist {
     /// requires that the first column corresponds to the word and the last column
     /// corresponds to a nonnegative score, such that higher scores indicate the
     /// word more frequently occurs.
-    pub fn init(path: &PathBuf) -> Arc<Self> {
-        println!("Loading wordlist...");
-        let file = File::open(path)
-            .expect(format!("Could not read wordlist at path '{:?}'", path).as_str());
+    pub fn init(path: &PathBuf) -> Result<Arc<Self>, io::Error> {
+        Wordlist::parse(&fs::read_to_string(path)?)
+    }
+
+    /// Initialize a `Wordlist` from `text` in the same format as a wordlist file, see `init`.
+    pub fn parse(text: &str) -> Result<Arc<Self>, io::Error> {
+        let mut entries = vec![];
+        for (idx, line) in text.lines().enumerate() {
+            entries.extend(parse_entry(idx + 1, line)?);
+        }
+        Ok(Wordlist::from_entries(entries))
+    }
 
+    /// Initialize a `Wordlist` from `(word, score)` entries, where higher scores indicate the
+    /// word more frequently occurs. Words not of length `WORD_LENGTH` are filtered out.
+    ///
+    /// ```
+    /// use crustacean_wordle::words::Wordlist;
+    ///
+    /// let wordlist = Wordlist::from_entries([("crane", 300.0), ("slate", 200.0), ("toolong", 100.0)]);
+    /// assert_eq!(wordlist.index_of("slate"), Some(1));
+    /// assert!(wordlist.get_word("toolong").is_none());
+    /// ```
+    pub fn from_entries<S: AsRef<str>>(entries: impl IntoIterator<Item = (S, f64)>) -> Arc<Self> {
         let mut words: Vec<WordPtr> = vec![];
         let mut scores: Vec<f64> = vec![];
 
-        let lines = BufReader::new(file).lines();
-
-        lines.filter_map(|line| line.ok()).for_each(|line| {
-            let mut columns = line.split_whitespace();
-            let word = columns.next().expect("Could not read word from column");
-            let score: f64 = columns.last().unwrap_or("0").parse().unwrap();
-
-            // Check length of word.
-            if word.len() != WORD_LENGTH {
-                return;
-            }
+        for (word, score) in entries {
+            // Check length of word, and that it can be stored as a `Word`.
+            let word = match Word::try_from(word.as_ref()) {
+                Ok(word) if word.len() == WORD_LENGTH => word,
+                _ => continue,
+            };
 
             // Filter out words with too low of a frequency score.
             if score < FREQ_SCORE_THRESHOLD {
-                return;
+                continue;
             }
 
-            words.push(Arc::new(Word::from(word)));
+            words.push(Arc::new(word));
             scores.push(score);
-        });
+        }
 
-        let words = words;
         let scores = Wordlist::normalize_scores(scores);
 
-        println!("Loaded wordlist.");
+        Arc::new(Wordlist::from_words(words, scores))
+    }
+
+    /// Build a `Wordlist` from words and their (already normalized) scores, indexing
+    /// each word by its first occurrence.
+    pub(crate) fn from_words(words: Vec<WordPtr>, scores: Vec<f64>) -> Self {
+        let mut index = HashMap::with_capacity(words.len());
+        for (idx, word) in words.iter().enumerate() {
+            index.entry(word.as_str().to_string()).or_insert(idx);
+        }
 
-        Arc::new(Wordlist { words, scores })
+        Wordlist {
+            pattern_index: Arc::new(PatternIndex::build(&words)),
+            words: Arc::new(words),
+            scores: Arc::new(scores),
+            index,
+        }
     }
 
     /// Find the given `word` in the list and return Some(match) if it
     /// is found, else None.
     pub fn get_word(&self, word: &str) -> Option<WordPtr> {
-        self.words.iter().find(|&w| w.get_word() == word).cloned()
+        self.index_of(word)
+            .and_then(|idx| self.get_word_by_index(idx))
+    }
+
+    /// Return the index of the given `word` in the list, if it is found.
+    pub fn index_of(&self, word: &str) -> Option<usize> {
+        self.index.get(word).copied()
+    }
+
+    /// Return the word at index `idx` in the list, if it is in bounds.
+    pub fn get_word_by_index(&self, idx: usize) -> Option<WordPtr> {
+        self.words.get(idx).cloned()
+    }
+
+    /// Return up to `max` words in the list that are nearest to `word` by edit
+    /// distance, with ties broken by list order (i.e. by frequency).
+    pub fn suggestions(&self, word: &str, max: usize) -> Vec<WordPtr> {
+        let mut distances: Vec<(usize, &WordPtr)> = self
+            .words
+            .iter()
+            .map(|w| (strsim::levenshtein(w.as_str(), word), w))
+            .collect();
+        distances.sort_by_key(|(distance, _)| *distance);
+
+        distances
+            .into_iter()
+            .take(max)
+            .map(|(_, w)| w.clone())
+            .collect()
     }
 
     pub fn get_word_slice(&self) -> &[WordPtr] {
@@ -298,10 +445,6 @@ impl Wordlist {
         }
 
         let num = scores.len() as f64;
-        let min = scores.iter().fold(f64::INFINITY, |a, b| f64::min(a, *b));
-        let max = scores
-            .iter()
-            .fold(f64::NEG_INFINITY, |a, b| f64::max(a, *b));
         let sum: f64 = scores.iter().sum();
         let mean = sum / num;
         let variance: f64 = scores
@@ -311,11 +454,6 @@ impl Wordlist {
             / num;
         let stddev = variance.sqrt();
 
-        println!(
-            "N: {}, sum: {}, min: {}, max: {}, mean: {}, variance: {}, stddev: {}",
-            num, sum, min, max, mean, variance, stddev
-        );
-
         let z_scores: Vec<f64> = scores
             .into_iter()
             .map(|score| {
@@ -332,106 +470,340 @@ impl Wordlist {
     }
 }
 
+/// Sublists with fewer members than this per block of `BLOCK_BITS` words are filtered word by
+/// word rather than through the `PatternIndex`.
+const SPARSE_WORDS_PER_BLOCK: usize = 2;
+
+/// A subset of a `Wordlist`, stored as a bitset over the indices of the parent's words, which
+/// it shares. The words and scores themselves are only gathered when first requested.
 #[derive(Default)]
 pub struct SubWordlist {
-    words: Vec<WordPtr>,
-    scores: Vec<f64>,
+    parent_words: Arc<Vec<WordPtr>>,
+    parent_scores: Arc<Vec<f64>>,
+    parent_index: Arc<PatternIndex>,
+    members: WordBitset,
+    words: OnceLock<Vec<WordPtr>>,
+    scores: OnceLock<Vec<f64>>,
 }
 
 impl HasWords for SubWordlist {
     fn possible_words(&self) -> &[WordPtr] {
-        &self.words
+        self.words.get_or_init(|| {
+            self.members
+                .iter()
+                .map(|idx| self.parent_words[idx].clone())
+                .collect()
+        })
+    }
+
+    fn num_words(&self) -> usize {
+        self.members.count()
     }
 }
 
 impl HasWordScores for SubWordlist {
     fn possible_scores(&self) -> &[f64] {
-        &self.scores
+        self.scores.get_or_init(|| {
+            self.members
+                .iter()
+                .map(|idx| self.parent_scores[idx])
+                .collect()
+        })
     }
 }
 
-impl CanPatternFilter for SubWordlist {}
+impl CanPatternFilter for SubWordlist {
+    fn filter_indexed(&self, keep: &dyn Fn(usize, &Word) -> bool) -> Arc<SubWordlist> {
+        let mut members = WordBitset::empty(self.parent_words.len());
+        for idx in self.members.iter() {
+            if keep(idx, &self.parent_words[idx]) {
+                members.insert(idx);
+            }
+        }
 
-impl SubWordlist {}
+        Arc::new(self.with_members(members))
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::collections::HashMap;
+    fn word_indices(&self) -> Vec<usize> {
+        self.members.iter().collect()
+    }
 
-    #[test]
-    fn test_matches() {
-        let pattern = Pattern {
-            disallowed: LetterBitmask::compute_bitmask(vec!['a', 'b', 'c'].iter()),
-            must_contain: HashMap::from_iter(vec![('d', 2_usize), ('e', 1_usize)]),
-            constraints: HashMap::from_iter(vec![
-                (0, PlaceConstraint::IsChar('d')),
-                (
-                    1,
-                    PlaceConstraint::IsNotChars(vec!['d', 'e'].to_letter_bitmask()),
-                ),
-                (2, PlaceConstraint::IsChar('d')),
-            ]),
+    fn count_pattern(&self, pattern: &Pattern) -> usize {
+        if self.is_sparse() {
+            return self
+                .members
+                .iter()
+                .filter(|&idx| self.parent_words[idx].matches(pattern))
+                .count();
+        }
+        self.parent_index.count(Some(&self.members), pattern)
+    }
+
+    fn filter_pattern(&self, pattern: &Pattern) -> Arc<SubWordlist> {
+        if self.is_sparse() {
+            return self.filter_indexed(&|_, word| word.matches(pattern));
+        }
+        let members = self.parent_index.matching(Some(&self.members), pattern);
+        Arc::new(self.with_members(members))
+    }
+}
+
+impl SubWordlist {
+    /// A sublist of `parent` containing the words at the indices in `members`.
+    pub(crate) fn new(parent: &Wordlist, members: WordBitset) -> Self {
+        SubWordlist {
+            parent_words: parent.words.clone(),
+            parent_scores: parent.scores.clone(),
+            parent_index: parent.pattern_index.clone(),
+            members,
+            ..Default::default()
+        }
+    }
+
+    /// Whether there are so few members that checking each is quicker than going through every
+    /// block of the parent's `PatternIndex`.
+    fn is_sparse(&self) -> bool {
+        self.num_words() < self.members.blocks().len() * SPARSE_WORDS_PER_BLOCK
+    }
+
+    /// A sublist of the same parent containing the words at the indices in `members`.
+    fn with_members(&self, members: WordBitset) -> Self {
+        SubWordlist {
+            parent_words: self.parent_words.clone(),
+            parent_scores: self.parent_scores.clone(),
+            parent_index: self.parent_index.clone(),
+            members,
+            ..Default::default()
+        }
+    }
+}
+
+/// Bitsets over the words of a `Wordlist`, from which the words matching a `Pattern` are found
+/// `BLOCK_BITS` words at a time, with a bitwise operation per constraint instead of a check per
+/// word.
+#[derive(Default)]
+struct PatternIndex {
+    /// The number of words indexed.
+    len: usize,
+    /// The length of the longest word indexed.
+    max_len: usize,
+    /// The words with each letter at each position, at `position * ALPHABET_SIZE + letter`.
+    at_position: Vec<WordBitset>,
+    /// The words with more than `count` of each letter, at `letter * max_len + count`.
+    more_than: Vec<WordBitset>,
+}
+
+impl PatternIndex {
+    /// Index `words`.
+    fn build(words: &[WordPtr]) -> Self {
+        let max_len = words.iter().map(|word| word.len()).max().unwrap_or(0);
+        let mut at_position = vec![WordBitset::empty(words.len()); max_len * ALPHABET_SIZE];
+        let mut more_than = vec![WordBitset::empty(words.len()); ALPHABET_SIZE * max_len];
+
+        for (idx, word) in words.iter().enumerate() {
+            for (position, &ch) in word.as_bytes().iter().enumerate() {
+                at_position[position * ALPHABET_SIZE + letter_index(ch)].insert(idx);
+            }
+            for (letter, &count) in word.counts.iter().enumerate() {
+                for fewer in 0..count as usize {
+                    more_than[letter * max_len + fewer].insert(idx);
+                }
+            }
+        }
+
+        PatternIndex {
+            len: words.len(),
+            max_len,
+            at_position,
+            more_than,
+        }
+    }
+
+    /// The number of words among `members` (or among all words if None) matching `pattern`.
+    fn count(&self, members: Option<&WordBitset>, pattern: &Pattern) -> usize {
+        let Some(query) = self.query(pattern) else {
+            return 0;
         };
 
-        assert!(!Word::from("ded").matches(&pattern));
-        assert!(!Word::from("dede").matches(&pattern));
-        assert!(!Word::from("ddde").matches(&pattern));
-        assert!(!Word::from("dcde").matches(&pattern));
-        assert!(Word::from("dfde").matches(&pattern));
-
-        let pattern = Pattern {
-            disallowed: LetterBitmask::compute_bitmask(vec!['y', 's', 'h', 'a', 't', 'z'].iter()),
-            must_contain: HashMap::from_iter(vec![]),
-            constraints: HashMap::from_iter(vec![
-                (
-                    0,
-                    PlaceConstraint::IsNotChars(vec!['w'].to_letter_bitmask()),
-                ),
-                (1, PlaceConstraint::IsChar('e')),
-                (3, PlaceConstraint::IsChar('e')),
-            ]),
+        (0..self.len.div_ceil(BLOCK_BITS))
+            .map(|block_idx| self.match_block(members, block_idx, &query).count_ones() as usize)
+            .sum()
+    }
+
+    /// The words among `members` (or among all words if None) matching `pattern`.
+    fn matching(&self, members: Option<&WordBitset>, pattern: &Pattern) -> WordBitset {
+        let num_blocks = self.len.div_ceil(BLOCK_BITS);
+        let blocks = match self.query(pattern) {
+            Some(query) => (0..num_blocks)
+                .map(|block_idx| self.match_block(members, block_idx, &query))
+                .collect(),
+            None => vec![0; num_blocks],
         };
+        WordBitset::from_blocks(blocks, self.len)
+    }
 
-        assert!(Word::from("rewed").matches(&pattern));
-        assert!(Word::from("beweded").matches(&pattern));
-        assert!(!Word::from("zeweded").matches(&pattern));
-
-        let pattern = Pattern {
-            disallowed: LetterBitmask::compute_bitmask(
-                vec!['t', 'b', 'i', 'n', 'g', 's', 'z', 'e', 'l', 'u', 'y', 'r'].iter(),
-            ),
-            must_contain: HashMap::from_iter(vec![('a', 1), ('o', 1), ('h', 1), ('c', 1)]),
-            constraints: HashMap::from_iter(vec![
-                (
-                    0,
-                    PlaceConstraint::IsNotChars(vec!['s', 'l', 'b', 'a'].to_letter_bitmask()),
-                ),
-                (1, PlaceConstraint::IsChar('o')),
-                (
-                    2,
-                    PlaceConstraint::IsNotChars(vec!['a', 'n', 't', 'y'].to_letter_bitmask()),
-                ),
-                (
-                    3,
-                    PlaceConstraint::IsNotChars(vec!['r', 'a', 'o', 'g'].to_letter_bitmask()),
-                ),
-                (
-                    4,
-                    PlaceConstraint::IsNotChars(vec!['e', 'c', 'h', 'y'].to_letter_bitmask()),
-                ),
-            ]),
+    /// The bitsets in `more_than` that words matching `pattern` must or must not be in, and the
+    /// letters they must not have at each position. This is None if no word can match.
+    fn query(&self, pattern: &Pattern) -> Option<IndexQuery> {
+        let mut query = IndexQuery {
+            required: [0; ALPHABET_SIZE],
+            num_required: 0,
+            excluded: [0; ALPHABET_SIZE],
+            num_excluded: 0,
+            disallowed: [0; MAX_WORD_LENGTH],
+        };
+
+        // Letters that may not occur at all are ruled out by their count, so they need not be
+        // ruled out at each position as well.
+        let mut absent: LetterBitmask = 0;
+        for letter in 0..ALPHABET_SIZE {
+            let min = pattern.min_counts[letter] as usize;
+            let max = pattern.max_counts[letter] as usize;
+            if min > self.max_len {
+                return None;
+            }
+            if min > 0 {
+                query.required[query.num_required] = letter * self.max_len + min - 1;
+                query.num_required += 1;
+            }
+            if max < self.max_len {
+                query.excluded[query.num_excluded] = letter * self.max_len + max;
+                query.num_excluded += 1;
+            }
+            if max == 0 {
+                absent |= 1 << letter;
+            }
+        }
+
+        for (disallowed, allowed) in query.disallowed.iter_mut().zip(&pattern.allowed) {
+            *disallowed = !allowed & ALL_LETTERS & !absent;
+        }
+
+        Some(query)
+    }
+
+    /// The block at `block_idx` of the words among `members` (or among all words if None)
+    /// matching `query`.
+    #[inline]
+    fn match_block(
+        &self,
+        members: Option<&WordBitset>,
+        block_idx: usize,
+        query: &IndexQuery,
+    ) -> u64 {
+        let mut block = match members {
+            Some(members) => members.blocks()[block_idx],
+            None => WordBitset::full_block(self.len, block_idx),
         };
 
-        assert!(Word::from("mocha").matches(&pattern));
-        assert!(!Word::from("azygy").matches(&pattern));
-        assert!(!Word::from("bocha").matches(&pattern));
+        for &idx in &query.required[..query.num_required] {
+            if block == 0 {
+                return 0;
+            }
+            block &= self.more_than[idx].blocks()[block_idx];
+        }
+        for &idx in &query.excluded[..query.num_excluded] {
+            if block == 0 {
+                return 0;
+            }
+            block &= !self.more_than[idx].blocks()[block_idx];
+        }
+
+        for (position, &disallowed) in query.disallowed.iter().enumerate().take(self.max_len) {
+            let mut disallowed = disallowed;
+            while disallowed != 0 && block != 0 {
+                let letter = disallowed.trailing_zeros() as usize;
+                disallowed &= disallowed - 1;
+                block &= !self.at_position[position * ALPHABET_SIZE + letter].blocks()[block_idx];
+            }
+        }
+
+        block
+    }
+}
+
+/// The constraints of a `Pattern` resolved against a `PatternIndex`, see `PatternIndex::query`.
+struct IndexQuery {
+    required: [usize; ALPHABET_SIZE],
+    num_required: usize,
+    excluded: [usize; ALPHABET_SIZE],
+    num_excluded: usize,
+    disallowed: [LetterBitmask; MAX_WORD_LENGTH],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::Guess;
+
+    /// The bitmask of all letters in `letters`.
+    fn mask(letters: &str) -> LetterBitmask {
+        letters.chars().collect::<Vec<char>>().to_letter_bitmask()
+    }
+
+    /// A pattern with the letters in `disallowed` ruled out entirely.
+    fn pattern_without(disallowed: &str) -> Pattern {
+        let mut pattern = Pattern::default();
+        for ch in disallowed.chars() {
+            pattern.max_counts[letter_index(ch as u8)] = 0;
+        }
+        pattern
+    }
+
+    #[test]
+    fn test_matches() {
+        let mut pattern = pattern_without("abc");
+        pattern.min_counts[letter_index(b'd')] = 2;
+        pattern.min_counts[letter_index(b'e')] = 1;
+        pattern.allowed[0] = mask("d");
+        pattern.allowed[1] &= !mask("de");
+        pattern.allowed[2] = mask("d");
+
+        assert!(!Word::try_from("ded").unwrap().matches(&pattern));
+        assert!(!Word::try_from("dede").unwrap().matches(&pattern));
+        assert!(!Word::try_from("ddde").unwrap().matches(&pattern));
+        assert!(!Word::try_from("dcde").unwrap().matches(&pattern));
+        assert!(Word::try_from("dfde").unwrap().matches(&pattern));
+
+        let mut pattern = pattern_without("yshatz");
+        pattern.allowed[0] &= !mask("w");
+        pattern.allowed[1] = mask("e");
+        pattern.allowed[3] = mask("e");
+
+        assert!(Word::try_from("rewed").unwrap().matches(&pattern));
+        assert!(Word::try_from("beweded").unwrap().matches(&pattern));
+        assert!(!Word::try_from("zeweded").unwrap().matches(&pattern));
+
+        let mut pattern = pattern_without("tbingszeluyr");
+        for ch in "aohc".bytes() {
+            pattern.min_counts[letter_index(ch)] = 1;
+        }
+        pattern.allowed[0] &= !mask("slba");
+        pattern.allowed[1] = mask("o");
+        pattern.allowed[2] &= !mask("anty");
+        pattern.allowed[3] &= !mask("raog");
+        pattern.allowed[4] &= !mask("echy");
+
+        assert!(Word::try_from("mocha").unwrap().matches(&pattern));
+        assert!(!Word::try_from("azygy").unwrap().matches(&pattern));
+        assert!(!Word::try_from("bocha").unwrap().matches(&pattern));
+
+        // A gray duplicate 'e' caps the count of 'e' at one.
+        let answer = Word::try_from("those").unwrap();
+        let guess = Arc::new(Word::try_from("geese").unwrap());
+        let pattern = Pattern::default().ingest(&Guess {
+            guess: guess.letters().collect(),
+            outcome: answer.outcome_of_guess(guess.clone()),
+        });
+
+        assert!(answer.matches(&pattern));
+        assert!(!Word::try_from("ethse").unwrap().matches(&pattern));
     }
 
     #[test]
     fn test_outcome_of_guess() {
-        let word = Word::from("abccdeefxr");
-        let guess = Arc::new(Word::from("azdcccferr"));
+        let word = Word::try_from("abccdeefxr").unwrap();
+        let guess = Arc::new(Word::try_from("azdcccferr").unwrap());
         let outcome = word.outcome_of_guess(guess);
         assert_eq!(
             outcome,
@@ -449,4 +821,175 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_try_from() {
+        let word = Word::try_from("CrAnE").unwrap();
+        assert_eq!(word.as_str(), "crane");
+        assert!(Word::try_from("crâne").is_err());
+        assert!(Word::try_from("cr4ne").is_err());
+        assert!(Word::try_from("a".repeat(MAX_WORD_LENGTH)).is_ok());
+        assert!(Word::try_from("a".repeat(MAX_WORD_LENGTH + 1)).is_err());
+    }
+
+    #[test]
+    fn test_get_word() {
+        let words: Vec<WordPtr> = vec!["crane", "slate", "crane", "mocha"]
+            .into_iter()
+            .map(|word| Arc::new(Word::try_from(word).unwrap()))
+            .collect();
+        let wordlist = Wordlist::from_words(words, vec![0_f64; 4]);
+
+        assert_eq!(wordlist.index_of("crane"), Some(0));
+        assert_eq!(wordlist.index_of("mocha"), Some(3));
+        assert_eq!(wordlist.index_of("xyzzy"), None);
+        assert_eq!(
+            wordlist.get_word("slate").map(|word| word.get_word()),
+            Some("slate".to_string())
+        );
+        assert!(wordlist.get_word("xyzzy").is_none());
+        assert!(wordlist.get_word_by_index(4).is_none());
+    }
+
+    #[test]
+    fn test_parse() {
+        let wordlist = Wordlist::parse("crane 1 300\n\nslate 200\nmocha\n").unwrap();
+        assert_eq!(wordlist.possible_words().len(), 3);
+        assert_eq!(wordlist.index_of("mocha"), Some(2));
+
+        let err = Wordlist::parse("crane 300\nslate lots\n").err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        assert!(err.to_string().contains("line 2"));
+    }
+
+    #[test]
+    fn test_suggestions() {
+        let words: Vec<WordPtr> = vec!["mocha", "crane", "frame", "slate", "crate", "cramp"]
+            .into_iter()
+            .map(|word| Arc::new(Word::try_from(word).unwrap()))
+            .collect();
+        let wordlist = Wordlist::from_words(words, vec![0_f64; 6]);
+        let suggestions = |word: &str, max: usize| -> Vec<String> {
+            wordlist
+                .suggestions(word, max)
+                .iter()
+                .map(|word| word.get_word())
+                .collect()
+        };
+
+        assert_eq!(suggestions("crame", 3), vec!["crane", "frame", "crate"]);
+        assert_eq!(
+            suggestions("crame", 10),
+            vec!["crane", "frame", "crate", "cramp", "slate", "mocha"]
+        );
+        assert!(suggestions("crame", 0).is_empty());
+    }
+
+    /// The outcome of `guess` against `word`, as originally computed by counting the letters of
+    /// `word` in a map: greens first, then yellows while unmatched copies of a letter remain.
+    fn reference_outcome(word: &str, guess: &str) -> Vec<TileOutcome> {
+        let word: Vec<char> = word.chars().collect();
+        let guess: Vec<char> = guess.chars().collect();
+        let mut outcomes = vec![TileOutcome::Gray; guess.len()];
+
+        let mut counts: HashMap<char, i32> = HashMap::new();
+        for ch in &word {
+            *counts.entry(*ch).or_default() += 1;
+        }
+
+        for idx in 0..guess.len() {
+            if word[idx] == guess[idx] {
+                outcomes[idx] = TileOutcome::Green;
+                *counts.get_mut(&word[idx]).unwrap() -= 1;
+            }
+        }
+
+        for (idx, ch) in guess.iter().enumerate() {
+            if outcomes[idx] == TileOutcome::Gray && counts.get(ch).is_some_and(|n| *n > 0) {
+                *counts.get_mut(ch).unwrap() -= 1;
+                outcomes[idx] = TileOutcome::Yellow;
+            }
+        }
+
+        outcomes
+    }
+
+    #[test]
+    fn test_outcome_code() {
+        use crate::game::{decode_outcome, encode_outcome};
+        use rayon::prelude::*;
+
+        let data_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("data");
+        let guesslist = Wordlist::init(&data_dir.join("guesslist.txt")).unwrap();
+        let answerlist = Wordlist::init(&data_dir.join("answerlist.txt")).unwrap();
+
+        answerlist.possible_words().par_iter().for_each(|answer| {
+            for (idx, guess) in guesslist.possible_words().iter().enumerate() {
+                let outcome = answer.outcome_of_guess(guess.clone());
+                let code = answer.outcome_code(guess);
+                assert_eq!(code, encode_outcome(&outcome), "{} vs {}", guess, answer);
+                assert_eq!(decode_outcome(code, guess.len()), outcome);
+
+                // The reference is slow, so check it against every fourth guess only.
+                if idx % 4 == 0 {
+                    let expected = reference_outcome(answer.as_str(), guess.as_str());
+                    assert_eq!(outcome, expected, "{} vs {}", guess, answer);
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn test_pattern_index() {
+        use rand::{Rng, SeedableRng};
+        use rand_chacha::ChaCha8Rng;
+
+        let data_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("data");
+        let guesslist = Wordlist::init(&data_dir.join("guesslist.txt")).unwrap();
+        let answerlist = Wordlist::init(&data_dir.join("answerlist.txt")).unwrap();
+        let thirds = answerlist.filter_indexed(&|idx, _| idx % 3 == 0);
+
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        for _ in 0..100 {
+            let answer = answerlist.random_word_with(&mut rng).unwrap();
+            let mut pattern = Pattern::default();
+            let mut extant: Arc<dyn CanPatternFilter + Send + Sync> = answerlist.clone();
+
+            for _ in 0..rng.gen_range(1..=4) {
+                let guess = guesslist.random_word_with(&mut rng).unwrap();
+                pattern = pattern.ingest(&Guess {
+                    guess: guess.letters().collect(),
+                    outcome: answer.outcome_of_guess(guess),
+                });
+
+                let expected: Vec<WordPtr> = answerlist
+                    .possible_words()
+                    .iter()
+                    .filter(|word| word.matches(&pattern))
+                    .cloned()
+                    .collect();
+                let expected_thirds: Vec<WordPtr> = thirds
+                    .possible_words()
+                    .iter()
+                    .filter(|word| word.matches(&pattern))
+                    .cloned()
+                    .collect();
+
+                assert_eq!(answerlist.count_pattern(&pattern), expected.len());
+                assert_eq!(
+                    answerlist.filter_pattern(&pattern).possible_words(),
+                    expected
+                );
+                assert_eq!(thirds.count_pattern(&pattern), expected_thirds.len());
+                assert_eq!(
+                    thirds.filter_pattern(&pattern).possible_words(),
+                    expected_thirds
+                );
+
+                extant = extant.filter_pattern(&pattern);
+                assert_eq!(extant.possible_words(), expected);
+                assert_eq!(extant.num_words(), expected.len());
+            }
+        }
+    }
 }